@@ -1,6 +1,8 @@
 
 #[cfg(test)] #[macro_use] extern crate quickcheck;
 
+#[cfg(feature = "rand")] extern crate rand;
+
 #[cfg(test)] use quickcheck::Arbitrary;
 #[cfg(test)] use quickcheck::Gen;
 
@@ -15,6 +17,15 @@ use std::ops::Sub;
 use std::cmp::Ordering;
 use std::cmp::Ord;
 use std::iter::Sum;
+use std::ops::BitAnd;
+use std::ops::BitOr;
+use std::ops::BitXor;
+use std::error;
+use std::str::FromStr;
+use std::mem;
+use std::iter;
+use std::ops::MulAssign;
+use std::io;
 
 /// Little-endian arbitrarily-sized unsigned integer
 #[derive(Eq, Debug, Clone)]
@@ -22,32 +33,55 @@ pub struct NonSmallInt { digits: Vec<u8> }
 
 const RADIX: u64 = 10;
 
+const U8_MAX_DIGITS: &[u8] = b"255";
+const U16_MAX_DIGITS: &[u8] = b"65535";
+const U32_MAX_DIGITS: &[u8] = b"4294967295";
+const U64_MAX_DIGITS: &[u8] = b"18446744073709551615";
+const U128_MAX_DIGITS: &[u8] = b"340282366920938463463374607431768211455";
+
+/// Miller-Rabin witnesses, also used for trial division
+const PRIME_WITNESSES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Verhoeff's multiplication table of the dihedral group D5
+const VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]
+];
+
+/// Verhoeff's permutation table, row i applying to the digit i places from the right
+const VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8]
+];
+
+/// Inverses in D5 under VERHOEFF_D
+const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
 impl NonSmallInt {
 
     /// Constructs from a u64
     pub fn of(n: u64) -> NonSmallInt {
         let str_digits = format!("{}", n);
-        NonSmallInt::parse(&str_digits).unwrap()
+        str_digits.parse().expect("Failed to parse a u64")
     }
 
-    /// Parses from a radix 10 number
+    /// Parses from a radix 10 number, or None if it's empty or has a non-digit
     pub fn parse(n: &str) -> Option<NonSmallInt> {
-        let mut digits = Vec::new();
-        let mut is_number = true;
-        for c in n.trim().chars() {
-            if c.is_digit(RADIX as u32) {
-                digits.push(c.to_digit(RADIX as u32).expect("Failed to parse digit") as u8)
-            } else {
-                is_number = false;
-            }
-        };
-        digits.reverse();
-
-        if is_number {
-            Some(NonSmallInt { digits: digits})
-        } else {
-            None
-        }
+        n.parse().ok()
     }
 
     /// Number of significant digits
@@ -77,519 +111,2785 @@ impl NonSmallInt {
     }
 
     pub fn is_zero(&self) -> bool {
-        self.digits.len() == 0 || self.digits.iter().all(|&n| n == 0)
+        self.digits.is_empty() || self.digits.iter().all(|&n| n == 0)
     }
 
-    /// Returns (quotient, remainder)
-    fn div_u32(&self, rhs: u32) -> Option<(NonSmallInt, NonSmallInt)> {
-        if rhs == 0 {
-            None
-        } else {
-            let mut quotient = Vec::new();
-            let mut carry = 0u64;
-            for digit in self.digits.iter().rev() {
-                let temp: u64 = carry * RADIX + (*digit as u64);
-                let out: u8 = (temp / rhs as u64) as u8;
-                carry = temp % (rhs as u64);
-                quotient.insert(0, out);
-            }
-            let mut remainder = Vec::new();
-            while carry > 0 {
-                let out = carry % RADIX;
-                carry = carry / RADIX;
-                remainder.push(out as u8);
-            }
-            Some((NonSmallInt { digits: quotient }, NonSmallInt { digits: remainder }))
-        }
+    /// Whether the value is representable as a u8
+    pub fn fits_u8(&self) -> bool {
+        self.fits_max(U8_MAX_DIGITS)
     }
 
-    fn div_nsi(&self, rhs: &NonSmallInt) -> Option<(NonSmallInt, NonSmallInt)> {
-        if rhs.is_zero() {
-            None
-        } else if rhs.length(RADIX) == 1 {
-            self.div_u32(rhs.digits[0] as u32)
-        } else if self.length(RADIX) < rhs.length(RADIX) {
-            Some((NonSmallInt { digits: vec![] }, self.clone()))
+    /// Whether the value is representable as a u16
+    pub fn fits_u16(&self) -> bool {
+        self.fits_max(U16_MAX_DIGITS)
+    }
+
+    /// Whether the value is representable as a u32
+    pub fn fits_u32(&self) -> bool {
+        self.fits_max(U32_MAX_DIGITS)
+    }
+
+    /// Whether the value is representable as a u64
+    pub fn fits_u64(&self) -> bool {
+        self.fits_max(U64_MAX_DIGITS)
+    }
+
+    /// Whether the value is representable as a u128
+    pub fn fits_u128(&self) -> bool {
+        self.fits_max(U128_MAX_DIGITS)
+    }
+
+    /// Compares the significant digits against the radix 10 ASCII digits of a type's max
+    fn fits_max(&self, max_digits: &[u8]) -> bool {
+        let digits = self.significant_digits();
+        digits.len() < max_digits.len() ||
+            (digits.len() == max_digits.len() && digits.iter().rev().map(|&d| b'0' + d).le(max_digits.iter().cloned()))
+    }
+
+    /// Little-endian digits without the leading zeros
+    fn significant_digits(&self) -> &[u8] {
+        &self.digits[..self.length(RADIX)]
+    }
+
+    /// Product of the significant digits, zero when any of them is zero
+    pub fn digit_product(&self) -> NonSmallInt {
+        let digits = self.significant_digits();
+        if digits.is_empty() || digits.contains(&0) {
+            NonSmallInt::of(0)
         } else {
-            long_division(self, rhs)
+            digits.iter().fold(NonSmallInt::of(1), |acc, &d| acc * d as u32)
         }
     }
 
-    fn lt(&self, rhs: &NonSmallInt) -> bool {
-        if self.length(RADIX) < rhs.length(RADIX) {
-            true
-        } else {
-            let max_length = max(self.digits.len(), rhs.digits.len());
-            let lhs_digits = self.iter_digits(max_length).rev();
-            let rhs_digits = rhs.iter_digits(max_length).rev();
-            match lhs_digits.zip(rhs_digits).skip_while(|&(lhs_d, rhs_d)| lhs_d == rhs_d).next() {
-                None => false,
-                Some((lhs_d, rhs_d)) => lhs_d < rhs_d
+    /// Parses from a radix 10 ASCII byte slice, skipping the UTF-8 validation of `parse`.
+    ///
+    /// Only ASCII whitespace is trimmed, unlike `parse` which trims any Unicode whitespace.
+    pub fn from_ascii(bytes: &[u8]) -> Option<NonSmallInt> {
+        let start = bytes.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(bytes.len());
+        let end = bytes.iter().rposition(|b| !b.is_ascii_whitespace()).map_or(start, |ix| ix + 1);
+        if start == end {
+            return None;
+        }
+        let mut digits = Vec::with_capacity(end - start);
+        for &b in bytes[start..end].iter().rev() {
+            if b.is_ascii_digit() {
+                digits.push(b - b'0');
+            } else {
+                return None;
             }
         }
+        Some(NonSmallInt::from_le_digits(digits))
     }
 
-    /// Result or None for underflow
-    fn safe_sub(&self, rhs: &NonSmallInt) -> Option<NonSmallInt> {
-        let mut out = Vec::new();
-        let mut borrow = 0u32;
-        let max_length = max(self.digits.len(), rhs.digits.len());
-        for (l, r) in self.iter_digits(max_length).zip(rhs.iter_digits(max_length)) {
-            let diff: u32 = (RADIX as u32 + l as u32).wrapping_sub(r as u32 + borrow);
-            out.push((diff % RADIX as u32) as u8);
-            borrow = 1 - diff / RADIX as u32;
+    /// Smallest number greater than self with the same decimal digit sum.
+    ///
+    /// # Panics
+    ///
+    /// Panics for zero since no greater number has a digit sum of zero.
+    pub fn next_with_same_digit_sum(&self) -> NonSmallInt {
+        let mut digits = self.significant_digits().to_vec();
+        if digits.is_empty() {
+            panic!("No number greater than zero has a digit sum of zero")
         }
-        if borrow == 0 {
-            Some(NonSmallInt { digits: out })
-        } else {
-            None
+        digits.push(0);
+
+        // Bump the lowest digit that can take one unit from the digits below it
+        let mut lower_sum = 0u64;
+        let mut ix = 0;
+        while digits[ix] == RADIX as u8 - 1 || lower_sum == 0 {
+            lower_sum += digits[ix] as u64;
+            ix += 1;
         }
+        digits[ix] += 1;
+
+        // Regroup what's left below it into the smallest arrangement: nines at the bottom
+        let mut rest = lower_sum - 1;
+        for d in digits[..ix].iter_mut() {
+            let fill = min(rest, RADIX - 1);
+            *d = fill as u8;
+            rest -= fill;
+        }
+        NonSmallInt { digits }
     }
 
-    fn iter_digits(&self, length: usize) -> Digits {
-        Digits { nsi: self, next_ix: 0, next_back_ix: length as isize - 1, empty: length == 0 }
+    /// Streams the radix 10 representation to w in fixed-size chunks
+    pub fn write_decimal<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        const CHUNK_SIZE: usize = 4096;
+        let digits = self.significant_digits();
+        if digits.is_empty() {
+            return w.write_all(b"0");
+        }
+        let mut chunk = [0u8; CHUNK_SIZE];
+        for ds in digits.rchunks(CHUNK_SIZE) {
+            for (c, &d) in chunk.iter_mut().zip(ds.iter().rev()) {
+                *c = b'0' + d;
+            }
+            w.write_all(&chunk[..ds.len()])?;
+        }
+        Ok(())
     }
-}
 
-struct Digits<'a> { nsi: &'a NonSmallInt, next_ix: usize, next_back_ix: isize, empty: bool }
+    /// n!, allocating a fresh product at every step
+    pub fn factorial(n: u64) -> NonSmallInt {
+        (1..n+1).fold(NonSmallInt::of(1), |acc, i| &acc * &NonSmallInt::of(i))
+    }
 
-impl <'a> Iterator for Digits<'a> {
-    type Item = u8;
-    fn next(&mut self) -> Option<u8> {
-        let next_value = |d: &mut Digits| {
-            let out = if d.next_ix < d.nsi.digits.len() { d.nsi.digits[d.next_ix] } else { 0 };
-            d.next_ix += 1;
-            out
-        };
-        if !self.empty && self.next_ix <= self.next_back_ix as usize {
-            Some(next_value(self))
-        } else {
-            None
+    /// n!, multiplying a single accumulator in place.
+    ///
+    /// `factorial` allocates a new product (plus a shifted partial product per digit of the
+    /// factor) at each of the n steps, whereas this only reallocates when the accumulator's
+    /// digit vector outgrows its capacity, i.e. O(log(n!)) times.
+    pub fn factorial_streaming(n: u64) -> NonSmallInt {
+        let mut acc = NonSmallInt::of(1);
+        for i in 1..n+1 {
+            if i <= u32::MAX as u64 {
+                acc *= i as u32;
+            } else {
+                acc = &acc * &NonSmallInt::of(i);
+            }
         }
+        acc
     }
-}
 
-impl <'a> DoubleEndedIterator for Digits<'a> {
-    fn next_back(&mut self) -> Option<u8> {
-        let next_value = |d: &mut Digits| {
-            let out = if (d.next_back_ix as usize) < d.nsi.digits.len() { d.nsi.digits[d.next_back_ix as usize] } else { 0 };
-            d.next_back_ix -= 1;
-            out
-        };
-        if !self.empty && self.next_back_ix >= (self.next_ix as isize) {
-            Some(next_value(self))
-        } else {
-            None
-        }
+    /// Upper bound on the number of significant digits of self * other, for preallocating
+    pub fn product_digit_bound(&self, other: &NonSmallInt) -> usize {
+        self.length(RADIX) + other.length(RADIX)
     }
-}
 
-/// Implementation from http://surface.syr.edu/cgi/viewcontent.cgi?article=1162&context=eecs_techreports
-/// Requires 2 <= rhs.length() <= lhs.length()
-fn long_division(lhs: &NonSmallInt, rhs: &NonSmallInt) -> Option<(NonSmallInt, NonSmallInt)> {
+    /// self * self
+    pub fn square(&self) -> NonSmallInt {
+        self * self
+    }
 
-    if rhs.is_zero() {
-        None
-    } else {
-        trait IndexingIsHard<A> {
-            /// Returns default value for A if doesn't exist
-            fn lookup(&self, ix: usize) -> A;
+    /// self mod RADIX^n, i.e. the lowest n digits
+    pub fn mod_pow10(&self, n: usize) -> NonSmallInt {
+        let digits = self.significant_digits();
+        NonSmallInt { digits: digits[..min(n, digits.len())].to_vec() }
+    }
 
-            /// Resizes self if doesn't fit new value
-            fn put(&mut self, ix: usize, value: A);
+    /// Whether the square of self ends in the digits of self
+    pub fn is_automorphic(&self) -> bool {
+        self.square().mod_pow10(self.length(RADIX)) == *self
+    }
+
+    /// Sum of the decimal digits
+    pub fn digit_sum(&self) -> u64 {
+        self.digits.iter().map(|&d| d as u64).sum()
+    }
+
+    /// Repeatedly replaces the number with its digit sum until it has at most target_len digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if target_len is zero, which a non-zero digit sum can never reach.
+    pub fn digit_sum_until(&self, target_len: usize) -> NonSmallInt {
+        if target_len == 0 {
+            panic!("Target length must be at least one digit")
+        }
+        let mut out = self.clone();
+        while out.length(RADIX) > target_len {
+            out = NonSmallInt::of(out.digit_sum());
         }
+        out
+    }
 
-        static ZERO: u8 = 0;
+    /// The significant digits in reverse order, so 1230 becomes 321
+    pub fn reversed(&self) -> NonSmallInt {
+        let mut digits = self.significant_digits().to_vec();
+        digits.reverse();
+        NonSmallInt { digits }
+    }
 
-        impl IndexingIsHard<u8> for Vec<u8> {
-            fn lookup(&self, ix: usize) -> u8 {
-                *self.get(ix).unwrap_or(&ZERO)
+    /// Whether the significant digits of self are those of other in reverse order.
+    ///
+    /// Both numbers must have the same number of significant digits, so although
+    /// `100.reversed() == 1`, neither of 1 and 100 is the reverse of the other.
+    pub fn is_reverse_of(&self, other: &NonSmallInt) -> bool {
+        self.length(RADIX) == other.length(RADIX) && *self == other.reversed()
+    }
+
+    /// self^exp mod modulus by binary exponentiation
+    pub fn pow_mod(&self, exp: &NonSmallInt, modulus: &NonSmallInt) -> NonSmallInt {
+        let mut out = &NonSmallInt::of(1) % modulus;
+        let mut base = self % modulus;
+        let mut exp = exp.clone();
+        while !exp.is_zero() {
+            let (q, r) = exp.div_u32(2).expect("Division by Zero is not permitted");
+            if !r.is_zero() {
+                out = out.mul_mod(&base, modulus);
             }
-            fn put(&mut self, ix: usize, value: u8) {
-                if ix < self.len() {
-                    self[ix] = value;
-                } else {
-                    self.insert(ix, value);
-                }
+            base = base.mul_mod(&base, modulus);
+            exp = q;
+        }
+        out
+    }
+
+    /// Miller-Rabin test using the first `rounds` primes (at least 1, at most 12) as witnesses,
+    /// which is deterministic below 3.3 * 10^24
+    pub fn is_probable_prime(&self, rounds: u32) -> bool {
+        let one = NonSmallInt::of(1);
+        if *self <= one {
+            return false;
+        }
+        for &p in PRIME_WITNESSES.iter() {
+            if *self == NonSmallInt::of(p as u64) {
+                return true;
+            } else if (self % p).is_zero() {
+                return false;
             }
         }
 
-        let trial = |r: &Vec<u8>, d: &Vec<u8>, k: usize, m: usize| -> u8 {
-            let km = k + m;
-            let r3: u64 = ((r.lookup(km) as u64 * RADIX) + r.lookup(km-1) as u64) * RADIX + r.lookup(km-2) as u64;
-            let d2: u64 = d.lookup(m-1) as u64 * RADIX + d.lookup(m-2) as u64;
-            min(r3 / d2, RADIX - 1) as u8
-        };
+        let n_minus_one = self - &one;
+        let (d, s) = n_minus_one.factor_out_twos();
 
-        let smaller = |r: &Vec<u8>, dq: &Vec<u8>, k: usize, m: usize| -> bool {
-            let mut i = m;
-            let mut j = 0;
-            while i != j {
-                if r.lookup(i+k) != dq.lookup(i) {
-                    j = i;
-                } else {
-                    i = i - 1;
-                }
+        'witnesses: for &a in PRIME_WITNESSES.iter().take(max(rounds, 1) as usize) {
+            let mut x = NonSmallInt::of(a as u64).pow_mod(&d, self);
+            if x == one || x == n_minus_one {
+                continue;
             }
-            r.lookup(i+k) < dq.lookup(i)
-        };
-
-        let difference = |r: &mut Vec<u8>, dq: &Vec<u8>, k: usize, m: usize| {
-            let mut borrow: u64 = 0;
-            for i in 0..m+1 {
-                let diff: u64 = (RADIX + *r.get(i+k).unwrap_or(&ZERO) as u64).wrapping_sub(*dq.get(i).unwrap_or(&ZERO) as u64 + borrow);
-                if (i+k) < r.len() {
-                    r[i+k] = (diff % RADIX) as u8;
-                } else {
-                    r.insert(i+k, (diff % RADIX) as u8);
+            for _ in 1..s {
+                x = &x.square() % self;
+                if x == n_minus_one {
+                    continue 'witnesses;
                 }
-                borrow = 1 - diff / RADIX;
             }
-        };
+            return false;
+        }
+        true
+    }
 
-        let longdivide = |x: &NonSmallInt, y: &NonSmallInt| -> (NonSmallInt, NonSmallInt) {
-            let n = x.length(RADIX);
-            let m = y.length(RADIX);
+    /// Whether self is a non-palindromic prime whose digit reversal is also prime
+    pub fn is_emirp(&self) -> bool {
+        let rounds = PRIME_WITNESSES.len() as u32;
+        !self.is_palindrome() && self.is_probable_prime(rounds) && self.reversed().is_probable_prime(rounds)
+    }
 
-            let f: u8 = RADIX as u8 / (y.digits[m-1] + 1);
+    /// self % rhs as a u32, keeping only a running remainder instead of building a quotient
+    pub fn rem_u32(&self, rhs: u32) -> u32 {
+        if rhs == 0 {
+            panic!("Division by zero is not supported")
+        }
+        self.digits.iter().rev().fold(0u64, |r, &d| (r * RADIX + d as u64) % rhs as u64) as u32
+    }
 
-            let mut r = x * f as u32;
-            let d = y * f as u32;
-            let mut q = Vec::new();
+    /// self % p for each of the given primes, for cheap trial division pre-screening
+    pub fn small_residues(&self, primes: &[u32]) -> Vec<u32> {
+        primes.iter().map(|&p| self.rem_u32(p)).collect()
+    }
 
-            for k in (0..(n-m+1)).rev() {
-                let mut qt = trial(&r.digits, &d.digits, k, m);
-                let mut dq = &d * qt as u32;
-                if smaller(&r.digits, &dq.digits, k, m) {
-                    qt = qt - 1;
-                    dq = &d * qt as u32;
-                }
-                q.insert(0, qt as u8);
-                difference(&mut r.digits, &dq.digits, k, m)
+    /// self += rhs, reusing the digit vector of self
+    pub fn add_in_place(&mut self, rhs: &NonSmallInt) {
+        let rhs_digits = rhs.significant_digits();
+        if self.digits.len() < rhs_digits.len() {
+            self.digits.resize(rhs_digits.len(), 0);
+        }
+        let mut carry = 0u8;
+        for (ix, d) in self.digits.iter_mut().enumerate() {
+            if carry == 0 && ix >= rhs_digits.len() {
+                break;
             }
+            let temp = *d + rhs_digits.get(ix).cloned().unwrap_or(0) + carry;
+            *d = temp % RADIX as u8;
+            carry = temp / RADIX as u8;
+        }
+        if carry != 0 {
+            self.digits.push(carry);
+        }
+    }
 
-            r = r.div_u32(f as u32).expect("Division by Zero is not permitted").0;
+    /// Sums all the values into a single accumulator, unlike `Sum` which allocates at each step
+    pub fn sum_all(values: &[NonSmallInt]) -> NonSmallInt {
+        let mut acc = NonSmallInt::of(0);
+        for v in values {
+            acc.add_in_place(v);
+        }
+        acc
+    }
 
-            (NonSmallInt { digits: q }, r)
-        };
+    /// One step of Kaprekar's routine: with self zero-padded to width digits, the
+    /// descending arrangement of its digits minus the ascending one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self has more than width significant digits.
+    pub fn kaprekar_step(&self, width: usize) -> NonSmallInt {
+        let length = self.significant_digits().len();
+        if length > width {
+            panic!("Number has more than {} digits", width)
+        }
+        // The padding zeros trail the descending arrangement and vanish from the ascending one
+        let descending = self.digits_descending().times_radix(width - length);
+        descending.safe_sub(&self.digits_ascending()).expect("Descending arrangement is never the smaller one")
+    }
 
-        Some(longdivide(lhs, rhs))
+    /// Number of single-digit multiplications the schoolbook self * other performs
+    pub fn estimate_product_cost(&self, other: &NonSmallInt) -> u64 {
+        self.length(RADIX) as u64 * other.length(RADIX) as u64
     }
-}
 
-impl PartialEq for NonSmallInt {
-    fn eq(&self, other: &NonSmallInt) -> bool {
-        self.digits.iter().rev().skip_while(|&n| *n == 0).eq(other.digits.iter().rev().skip_while(|&n| *n == 0))
+    /// Constructs from factorial-base digits, least significant first, so digit i weighs i!.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any digit i is greater than i.
+    pub fn from_factoradic(digits: &[u32]) -> NonSmallInt {
+        if let Some((ix, d)) = digits.iter().enumerate().find(|&(ix, &d)| d as u64 > ix as u64) {
+            panic!("Factoradic digit {} at position {} is out of bounds", d, ix)
+        }
+        digits.iter().enumerate().rev().fold(NonSmallInt::of(0), |acc, (ix, &d)| {
+            acc * (ix as u32 + 1) + NonSmallInt::of(d as u64)
+        })
     }
-}
 
-impl <'a> Div for &'a NonSmallInt {
-    type Output = NonSmallInt;
-    fn div(self, rhs: &NonSmallInt) -> NonSmallInt {
-        match self.div_nsi(&rhs) {
-            None => panic!("Division by zero is not allowed"),
-            Some((q, _)) => q
+    /// Factorial-base digits, least significant first, so digit i weighs i!
+    pub fn to_factoradic(&self) -> Vec<u32> {
+        let mut digits = Vec::new();
+        let mut n = self.clone();
+        let mut divisor = 1u32;
+        loop {
+            digits.push(n.rem_u32(divisor));
+            n = &n / divisor;
+            divisor += 1;
+            if n.is_zero() {
+                return digits;
+            }
         }
     }
-}
 
-impl <'a> Div<u32> for &'a NonSmallInt {
-    type Output = NonSmallInt;
-    fn div(self, rhs: u32) -> NonSmallInt {
-        match self.div_u32(rhs) {
-            None => panic!("Division by zero is not allowed"),
-            Some((q, _)) => q
+    /// Sum of the divisors of the number with the given (prime, exponent) factorization
+    pub fn sigma_from_factors(factors: &[(NonSmallInt, u32)]) -> NonSmallInt {
+        let one = NonSmallInt::of(1);
+        factors.iter().fold(one.clone(), |acc, &(ref p, e)| {
+            let (q, _) = (&p.pow(e + 1) - &one).div_nsi(&(p - &one)).expect("Factors must be primes");
+            acc * q
+        })
+    }
+
+    /// Whether value, whose (prime, exponent) factorization is given, is the sum of its proper divisors
+    pub fn is_perfect_from_factors(factors: &[(NonSmallInt, u32)], value: &NonSmallInt) -> bool {
+        NonSmallInt::sigma_from_factors(factors) == value * 2u32
+    }
+
+    /// Numerator and denominator of the last convergent of the continued fraction
+    /// [a0; a1, a2, ...], or (1, 0) for no terms
+    pub fn continued_fraction_convergent(terms: &[u64]) -> (NonSmallInt, NonSmallInt) {
+        let (mut h, mut h_prev) = (NonSmallInt::of(1), NonSmallInt::of(0));
+        let (mut k, mut k_prev) = (NonSmallInt::of(0), NonSmallInt::of(1));
+        for &a in terms {
+            let h_next = &h * a + h_prev;
+            let k_next = &k * a + k_prev;
+            h_prev = h;
+            k_prev = k;
+            h = h_next;
+            k = k_next;
         }
+        (h, k)
     }
-}
 
-impl <'a> Rem for &'a NonSmallInt {
-    type Output = NonSmallInt;
-    fn rem(self, rhs: &NonSmallInt) -> NonSmallInt {
-        match self.div_nsi(rhs) {
-            None => panic!("Division by zero is not supported"),
-            Some((_, r)) => r
+    /// self - rhs or None for underflow
+    pub fn checked_sub(&self, rhs: &NonSmallInt) -> Option<NonSmallInt> {
+        self.safe_sub(rhs)
+    }
+
+    /// Whether self is an exact multiple of divisor, only zero being a multiple of zero
+    pub fn is_divisible_by(&self, divisor: &NonSmallInt) -> bool {
+        match self.div_nsi(divisor) {
+            None => self.is_zero(),
+            Some((_, r)) => r.is_zero()
         }
     }
-}
 
-impl <'a> Rem<u32> for &'a NonSmallInt {
-    type Output = NonSmallInt;
-    fn rem(self, rhs: u32) -> NonSmallInt {
-        match self.div_u32(rhs) {
-            None => panic!("Division by zero is not supported"),
-            Some((_, r)) => r
+    /// Whether self is start + k * step for some k >= 0
+    pub fn is_in_progression(&self, start: &NonSmallInt, step: &NonSmallInt) -> bool {
+        match self.checked_sub(start) {
+            None => false,
+            Some(offset) => offset.is_divisible_by(step)
         }
     }
-}
 
-impl <'a> Mul<u32> for &'a NonSmallInt {
-    type Output = NonSmallInt;
-    fn mul(self, rhs: u32) -> NonSmallInt {
-        let mut out_digits = Vec::new();
-        let mut carry = 0u64;
-        for digit in self.digits.iter() {
-            let temp: u64 = (rhs as u64) * (*digit as u64) + carry;
-            let out: u8 = (temp % RADIX) as u8;
-            carry = temp / RADIX;
-            out_digits.push(out);
+    /// Digits in the given radix, least significant first, with no digits for zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if radix is outside 2 to 256.
+    pub fn digits_radix(&self, radix: u32) -> Vec<u8> {
+        if !(2..=256).contains(&radix) {
+            panic!("Unsupported radix {}", radix)
         }
-        while carry != 0 {
-            let out: u8 = (carry % RADIX) as u8;
-            carry = carry / RADIX;
-            out_digits.push(out);
+        self.digits_radix_wide(radix).into_iter().map(|d| d as u8).collect()
+    }
+
+    /// Exponents of the powers of two summing to self, in increasing order
+    pub fn to_power_of_two_terms(&self) -> Vec<u32> {
+        self.digits_radix(2).iter().zip(0..).filter(|&(&b, _)| b == 1).map(|(_, e)| e).collect()
+    }
+
+    /// Product of all the values, multiplying balanced halves so the operands stay similarly sized
+    pub fn product_balanced(values: &[NonSmallInt]) -> NonSmallInt {
+        match values.len() {
+            0 => NonSmallInt::of(1),
+            1 => values[0].clone(),
+            n => {
+                let (left, right) = values.split_at(n / 2);
+                NonSmallInt::product_balanced(left) * NonSmallInt::product_balanced(right)
+            }
         }
-        NonSmallInt { digits: out_digits }
     }
-}
 
-impl Mul<u32> for NonSmallInt {
-    type Output = NonSmallInt;
-    fn mul(self, rhs: u32) -> NonSmallInt {
-        (&self).mul(rhs)
+    /// Constructs from digits in the given radix, most significant first, or None if the
+    /// radix is below 2 or a digit isn't below it
+    pub fn from_digits_radix(digits: &[u8], radix: u32) -> Option<NonSmallInt> {
+        if radix < 2 || digits.iter().any(|&d| d as u32 >= radix) {
+            None
+        } else {
+            Some(digits.iter().fold(NonSmallInt::of(0), |acc, &d| acc * radix + NonSmallInt::of(d as u64)))
+        }
     }
-}
 
-impl <'a> Mul for &'a NonSmallInt {
-    type Output = NonSmallInt;
-    fn mul(self, rhs: &NonSmallInt) -> NonSmallInt {
-        let mut out = NonSmallInt::of(0);
-        for (&rhs_d, ix) in rhs.digits.iter().zip(0..) {
-            let to_be_added = (self * (rhs_d as u32)).times_radix(ix);
-            out = out + to_be_added;
+    /// Greatest common divisor by Euclid's algorithm
+    pub fn gcd(&self, other: &NonSmallInt) -> NonSmallInt {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
         }
-        out
+        a
     }
-}
 
-impl <'a> Mul<NonSmallInt> for &'a NonSmallInt {
-    type Output = NonSmallInt;
-    fn mul(self, rhs: NonSmallInt) -> NonSmallInt {
-        self.mul(&rhs)
+    /// Smallest k > 0 with self^k = 1 (mod modulus), or None unless self and modulus are coprime
+    pub fn multiplicative_order(&self, modulus: &NonSmallInt) -> Option<NonSmallInt> {
+        let one = NonSmallInt::of(1);
+        if modulus.is_zero() || self.gcd(modulus) != one {
+            return None;
+        }
+        let base = self % modulus;
+        let one = &one % modulus;
+        let mut power = base.clone();
+        let mut k = NonSmallInt::of(1);
+        while power != one {
+            power = &(&power * &base) % modulus;
+            k.add_in_place(&NonSmallInt::of(1));
+        }
+        Some(k)
     }
-}
 
-impl Mul for NonSmallInt {
-    type Output = NonSmallInt;
-    fn mul(self, rhs: NonSmallInt) -> NonSmallInt {
-        (&self).mul(&rhs)
+    /// Whether self is the sum of its digits each raised to the number of digits
+    pub fn is_armstrong(&self) -> bool {
+        let digits = self.significant_digits();
+        let length = digits.len() as u32;
+        digits.iter().map(|&d| NonSmallInt::of(d as u64).pow(length)).sum::<NonSmallInt>() == *self
     }
-}
 
-impl <'a> Sub for &'a NonSmallInt {
-    type Output = NonSmallInt;
-    fn sub(self, rhs: &NonSmallInt) -> NonSmallInt {
-        match self.safe_sub(rhs) {
-            Some(r) => r,
-            None => panic!("NonSmallInt underflow")
+    /// The significant digits rotated right by the given number of positions, keeping the width
+    /// while rotating; zeros rotated to the front are then dropped, so 120 rotated by 1 is 12
+    pub fn rotate_digits(&self, by: usize) -> NonSmallInt {
+        let mut digits = self.significant_digits().to_vec();
+        if !digits.is_empty() {
+            let shift = by % digits.len();
+            digits.rotate_left(shift);
         }
+        NonSmallInt::from_le_digits(digits)
     }
-}
 
-impl <'a> Add for &'a NonSmallInt {
-    type Output = NonSmallInt;
-    fn add(self, rhs: &NonSmallInt) -> NonSmallInt {
-        let mut out = Vec::new();
-        let mut carry = 0u32;
-        let max_length = max(self.length(RADIX), rhs.length(RADIX));
-        for (ld, rd) in self.iter_digits(max_length).zip(rhs.iter_digits(max_length)) {
-            let temp: u32 = ld as u32 + rd as u32 + carry;
-            out.push((temp % RADIX as u32) as u8);
-            carry = temp / RADIX as u32;
+    /// Division by repeated subtraction, O(quotient), to cross-check long division against
+    #[cfg(feature = "slow-div")]
+    pub fn div_nsi_naive(&self, rhs: &NonSmallInt) -> Option<(NonSmallInt, NonSmallInt)> {
+        if rhs.is_zero() {
+            return None;
         }
-        if carry != 0 {
-            out.push((carry % RADIX as u32) as u8);
+        let mut quotient = NonSmallInt::of(0);
+        let mut remainder = self.clone();
+        let one = NonSmallInt::of(1);
+        while let Some(r) = remainder.safe_sub(rhs) {
+            remainder = r;
+            quotient.add_in_place(&one);
         }
-        NonSmallInt { digits: out }
+        Some((quotient, remainder))
     }
-}
 
-impl Add for NonSmallInt {
-    type Output = NonSmallInt;
-    fn add(self, rhs: NonSmallInt) -> NonSmallInt {
-        (&self).add(&rhs)
+    /// Number of divisors of the number with the given (prime, exponent) factorization
+    pub fn divisor_count_from_factors(factors: &[(NonSmallInt, u32)]) -> NonSmallInt {
+        factors.iter().fold(NonSmallInt::of(1), |acc, &(_, e)| acc * (e as u64 + 1))
     }
-}
 
-impl PartialOrd for NonSmallInt {
-    fn partial_cmp(&self, other: &NonSmallInt) -> Option<Ordering> {
-        Some(self.cmp(other))
+    /// The value as a u8, or u8::MAX if it doesn't fit
+    pub fn to_u8_saturating(&self) -> u8 {
+        self.to_saturating(U8_MAX_DIGITS, u8::MAX as u128) as u8
     }
-}
 
-impl Ord for NonSmallInt {
-    fn cmp(&self, other: &NonSmallInt) -> Ordering {
-        if self.lt(other) {
-            Ordering::Less
-        } else if self == other {
-            Ordering::Equal
+    /// The value as a u16, or u16::MAX if it doesn't fit
+    pub fn to_u16_saturating(&self) -> u16 {
+        self.to_saturating(U16_MAX_DIGITS, u16::MAX as u128) as u16
+    }
+
+    /// The value as a u32, or u32::MAX if it doesn't fit
+    pub fn to_u32_saturating(&self) -> u32 {
+        self.to_saturating(U32_MAX_DIGITS, u32::MAX as u128) as u32
+    }
+
+    /// The value as a u64, or u64::MAX if it doesn't fit
+    pub fn to_u64_saturating(&self) -> u64 {
+        self.to_saturating(U64_MAX_DIGITS, u64::MAX as u128) as u64
+    }
+
+    /// The value as a u128, or u128::MAX if it doesn't fit
+    pub fn to_u128_saturating(&self) -> u128 {
+        self.to_saturating(U128_MAX_DIGITS, u128::MAX)
+    }
+
+    /// The value if it fits under the type's max, given as ASCII digits and as a value, else max
+    fn to_saturating(&self, max_digits: &[u8], max: u128) -> u128 {
+        if self.fits_max(max_digits) {
+            self.significant_digits().iter().rev().fold(0u128, |acc, &d| acc * RADIX as u128 + d as u128)
         } else {
-            Ordering::Greater
+            max
         }
     }
-}
 
-impl fmt::Display for NonSmallInt {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_zero() {
-            write!(f, "0")
-        } else {
-            let mut result = write!(f, "");
-            for d in self.digits.iter().rev() {
-                result = write!(f, "{}", d);
+    /// The integer formed by concatenating the decimal digits of 1, 2, ..., n
+    pub fn champernowne_concat(n: u64) -> NonSmallInt {
+        let mut digits = Vec::new();
+        for i in (1..n+1).rev() {
+            let mut i = i;
+            while i > 0 {
+                digits.push((i % RADIX) as u8);
+                i /= RADIX;
             }
-            result
         }
+        NonSmallInt { digits }
     }
-}
 
-impl Sum for NonSmallInt {
-    fn sum<I>(iter: I) -> NonSmallInt where I: Iterator<Item = NonSmallInt> {
-        let mut acc = NonSmallInt::of(0);
-        for x in iter {
-            acc = acc + x;
-        }
-        acc
+    /// Largest significant digit and its position counted from the most significant digit,
+    /// preferring the most significant on ties; zero gives (0, 0)
+    pub fn max_digit(&self) -> (u8, usize) {
+        self.extreme_digit(|candidate, best| candidate > best)
     }
-}
 
-#[cfg(test)]
-mod tests {
+    /// Smallest significant digit and its position counted from the most significant digit,
+    /// preferring the most significant on ties; zero gives (0, 0)
+    pub fn min_digit(&self) -> (u8, usize) {
+        self.extreme_digit(|candidate, best| candidate < best)
+    }
 
-    use super::*;
+    /// The first digit from the top that no later digit beats
+    fn extreme_digit<F: Fn(u8, u8) -> bool>(&self, beats: F) -> (u8, usize) {
+        let mut digits = self.significant_digits().iter().rev().cloned().enumerate();
+        let first = match digits.next() {
+            None => return (0, 0),
+            Some((ix, d)) => (d, ix)
+        };
+        digits.fold(first, |best, (ix, d)| if beats(d, best.0) { (d, ix) } else { best })
+    }
 
-    impl Arbitrary for NonSmallInt {
-        fn arbitrary<G: Gen>(g: &mut G) -> NonSmallInt {
-            NonSmallInt::of(u64::arbitrary(g))
+    /// self + rhs, or None if the sum has more than max_digits significant digits
+    pub fn checked_add_bounded(&self, rhs: &NonSmallInt, max_digits: usize) -> Option<NonSmallInt> {
+        // The sum is at least as long as either operand, so skip the addition in that case
+        if max(self.length(RADIX), rhs.length(RADIX)) > max_digits {
+            return None;
+        }
+        let sum = self + rhs;
+        if sum.length(RADIX) > max_digits {
+            None
+        } else {
+            Some(sum)
         }
     }
 
-    #[derive(Clone, Debug)]
-    /// A NonSmallInt along with the same value as u64
-    struct MinimalNonSmallInt { nsi: NonSmallInt, n: u64 }
+    /// self % m for each of the moduli
+    pub fn residues_mod(&self, moduli: &[NonSmallInt]) -> Vec<NonSmallInt> {
+        moduli.iter().map(|m| self % m).collect()
+    }
 
-    impl MinimalNonSmallInt {
-        fn of(n: u64) -> MinimalNonSmallInt {
-            MinimalNonSmallInt { nsi: NonSmallInt::of(n), n: n }
+    /// x with self * x = 1 (mod modulus) by the extended Euclidean algorithm, or None unless
+    /// self and modulus are coprime
+    pub fn mod_inverse(&self, modulus: &NonSmallInt) -> Option<NonSmallInt> {
+        if modulus.is_zero() {
+            return None;
+        }
+        // Keeps r = t * self (mod modulus) for consecutive remainders r of Euclid's algorithm
+        let (mut r_prev, mut r) = (modulus.clone(), self % modulus);
+        let (mut t_prev, mut t) = (NonSmallInt::of(0), NonSmallInt::of(1));
+        while !r.is_zero() {
+            let (q, r_next) = r_prev.div_nsi(&r).expect("Division by Zero is not permitted");
+            let qt = &(&q * &t) % modulus;
+            let t_next = &(&t_prev + &(modulus - &qt)) % modulus;
+            r_prev = r;
+            r = r_next;
+            t_prev = t;
+            t = t_next;
+        }
+        if r_prev == NonSmallInt::of(1) {
+            Some(&t_prev % modulus)
+        } else {
+            None
         }
     }
 
-    impl Arbitrary for MinimalNonSmallInt {
-        fn arbitrary<G: Gen>(g: &mut G) -> MinimalNonSmallInt {
-            let n = u64::arbitrary(g);
-            MinimalNonSmallInt::of(n)
+    /// The smallest x with x = residues[i] (mod moduli[i]) for all i, by the Chinese Remainder
+    /// Theorem, or None if the lengths differ or the moduli aren't positive and pairwise coprime
+    pub fn crt(residues: &[NonSmallInt], moduli: &[NonSmallInt]) -> Option<NonSmallInt> {
+        if residues.len() != moduli.len() || moduli.iter().any(|m| m.is_zero()) {
+            return None;
         }
+        let product = moduli.iter().fold(NonSmallInt::of(1), |acc, m| &acc * m);
+        let mut x = NonSmallInt::of(0);
+        for (r, m) in residues.iter().zip(moduli) {
+            let partial = &product / m;
+            let inverse = (&partial % m).mod_inverse(m)?;
+            x.add_in_place(&(&(&(r % m) * &inverse) * &partial));
+        }
+        Some(&x % &product)
     }
 
-    #[derive(Clone, Debug)]
-    struct SmallInt { n: u8 }
+    /// The significant digits as chars, most significant first, with ['0'] for zero
+    pub fn to_digit_chars(&self) -> Vec<char> {
+        let digits = self.significant_digits();
+        if digits.is_empty() {
+            vec!['0']
+        } else {
+            digits.iter().rev().map(|&d| (b'0' + d) as char).collect()
+        }
+    }
 
-    impl Arbitrary for SmallInt {
-        fn arbitrary<G: Gen>(g: &mut G) -> SmallInt {
-            let n = u8::arbitrary(g) % 10;
-            SmallInt { n: n }
+    /// Sum of each significant digit times its weight, cycling through the weights from the
+    /// least significant digit; no weights give zero
+    pub fn weighted_digit_sum(&self, weights: &[u32]) -> u64 {
+        if weights.is_empty() {
+            return 0;
         }
+        self.significant_digits().iter().zip(weights.iter().cycle()).map(|(&d, &w)| d as u64 * w as u64).sum()
     }
 
-    quickcheck! {
+    /// Whether self is a non-zero EAN-13 (or ISBN-13) code with a valid check digit, taking
+    /// fewer than 13 digits as zero-padded on the left since leading zeros aren't stored
+    pub fn is_ean13_valid(&self) -> bool {
+        let length = self.length(RADIX);
+        0 < length && length <= 13 && self.weighted_digit_sum(&[1, 3]) % 10 == 0
+    }
 
-        fn counts_length_correctly(x: MinimalNonSmallInt) -> bool {
-            if x.n > 0 {
-                x.nsi.length(RADIX) == format!("{}", x.n).len()
-            } else {
-                x.nsi.length(RADIX) == 0
+    /// Floor of the square root by Newton's method
+    pub fn isqrt(&self) -> NonSmallInt {
+        if self.is_zero() {
+            return NonSmallInt::of(0);
+        }
+        // RADIX^ceil(length / 2) is never below the root, so the iteration decreases to it
+        let mut x = NonSmallInt::of(1).times_radix((self.length(RADIX) + 1) / 2);
+        loop {
+            let y = &(&x + &(self / &x)) / 2u32;
+            if y >= x {
+                return x;
             }
+            x = y;
         }
+    }
 
-        fn comparison(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
-            x.n.cmp(&y.n) == x.nsi.cmp(&y.nsi)
+    /// Floor of the geometric mean of self and other
+    pub fn geometric_mean_floor(&self, other: &NonSmallInt) -> NonSmallInt {
+        (self * other).isqrt()
+    }
+
+    /// n * (n - 1) * ... * (n - k + 1), the number of k-permutations of n, zero for k > n
+    pub fn falling_factorial(n: &NonSmallInt, k: u64) -> NonSmallInt {
+        if NonSmallInt::of(k) > *n {
+            return NonSmallInt::of(0);
+        }
+        let one = NonSmallInt::of(1);
+        let mut acc = one.clone();
+        let mut factor = n.clone();
+        for _ in 0..k {
+            acc = &acc * &factor;
+            factor = &factor - &one;
         }
+        acc
+    }
 
-        fn multiplies_by_u32(x: u32, y: u32) -> bool {
-            let xnsi = NonSmallInt::of(x as u64);
-            let expected = NonSmallInt::of((x * y) as u64);
+    /// Whether the significant digits read the same in both directions
+    pub fn is_palindrome(&self) -> bool {
+        let digits = self.significant_digits();
+        digits.iter().eq(digits.iter().rev())
+    }
 
-            &xnsi * y == expected
-        }
+    /// Whether self is a palindrome and passes `is_probable_prime` with the given rounds,
+    /// checking the cheap palindrome property first
+    pub fn is_palindromic_prime(&self, rounds: u32) -> bool {
+        self.is_palindrome() && self.is_probable_prime(rounds)
+    }
 
-        fn div_by_u32(x: MinimalNonSmallInt, y: u32) -> bool {
-            if y != 0 {
-                x.nsi.div_u32(y) == Some((NonSmallInt::of(x.n / y as u64), NonSmallInt::of(x.n % y as u64)))
-            } else {
-                x.nsi.div_u32(y) == None
+    /// self plus its digit reversal
+    pub fn reverse_add_step(&self) -> NonSmallInt {
+        self + &self.reversed()
+    }
+
+    /// Number of reverse-and-add steps (at least one, even from a palindrome) until a
+    /// palindrome is reached, or None if it isn't reached within max_steps
+    pub fn lychrel_resolves(&self, max_steps: u32) -> Option<u32> {
+        let mut n = self.clone();
+        for step in 1..max_steps+1 {
+            n = n.reverse_add_step();
+            if n.is_palindrome() {
+                return Some(step);
             }
         }
+        None
+    }
 
-        fn subtracts(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
-            if x.n >= y.n {
-                x.nsi.safe_sub(&y.nsi).unwrap() == NonSmallInt::of(x.n - y.n)
-            } else {
-                x.nsi.safe_sub(&y.nsi).is_none()
+    /// (self * other) % modulus, reducing both operands before multiplying
+    pub fn mul_mod(&self, other: &NonSmallInt, modulus: &NonSmallInt) -> NonSmallInt {
+        &(&(self % modulus) * &(other % modulus)) % modulus
+    }
+
+    /// The radix 10 representation split from the left into groups of the given sizes, with
+    /// whatever is left over as a final group.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any group size is zero.
+    pub fn grouped_digits(&self, group_sizes: &[usize]) -> Vec<String> {
+        if group_sizes.contains(&0) {
+            panic!("Digit groups must not be empty")
+        }
+        let chars = self.to_digit_chars();
+        let mut groups = Vec::new();
+        let mut rest = &chars[..];
+        for &size in group_sizes {
+            if rest.is_empty() {
+                break;
             }
+            let (group, tail) = rest.split_at(min(size, rest.len()));
+            groups.push(group.iter().collect());
+            rest = tail;
+        }
+        if !rest.is_empty() {
+            groups.push(rest.iter().collect());
         }
+        groups
+    }
 
-        fn full_division(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
-            let result = x.nsi.div_nsi(&y.nsi);
-            if y.n != 0 {
-                result == Some((NonSmallInt::of(x.n / y.n), NonSmallInt::of(x.n % y.n)))
-            } else {
-                result == None
-            }
+    /// self + 1
+    pub fn succ(&self) -> NonSmallInt {
+        let mut out = self.clone();
+        out.add_in_place(&NonSmallInt::of(1));
+        out
+    }
+
+    /// 1 + 2 + ... + n, as n * (n + 1) / 2
+    pub fn triangular(n: &NonSmallInt) -> NonSmallInt {
+        let (q, r) = (n * &n.succ()).div_rem_u32(2);
+        debug_assert!(r == 0, "n * (n + 1) is always even");
+        q
+    }
+
+    /// self / rhs, or None if rhs is zero or doesn't divide self exactly
+    pub fn div_exact(&self, rhs: &NonSmallInt) -> Option<NonSmallInt> {
+        match self.div_nsi(rhs) {
+            Some((q, ref r)) if r.is_zero() => Some(q),
+            _ => None
         }
+    }
 
-        fn displays(x: MinimalNonSmallInt) -> bool {
-            format!("{}", x.nsi) == format!("{}", x.n)
+    /// 1^2 + 2^2 + ... + n^2, as n * (n + 1) * (2n + 1) / 6
+    pub fn sum_of_squares(n: &NonSmallInt) -> NonSmallInt {
+        let product = &(n * &n.succ()) * &(n * 2u32).succ();
+        product.div_exact(&NonSmallInt::of(6)).expect("n * (n + 1) * (2n + 1) is always a multiple of 6")
+    }
+
+    /// The endless sequence 1, self, self^2, ..., each term multiplying the previous one
+    pub fn powers(&self) -> impl Iterator<Item = NonSmallInt> {
+        let base = self.clone();
+        iter::successors(Some(NonSmallInt::of(1)), move |p| Some(p * &base))
+    }
+
+    /// Whether self is the square of an integer
+    pub fn is_perfect_square(&self) -> bool {
+        self.isqrt().square() == *self
+    }
+
+    /// Whether self is a Fibonacci number, i.e. 5n^2 + 4 or 5n^2 - 4 is a perfect square
+    pub fn is_fibonacci(&self) -> bool {
+        let four = NonSmallInt::of(4);
+        let five_squared = self.square() * 5u32;
+        (&five_squared + &four).is_perfect_square() ||
+            five_squared.safe_sub(&four).map_or(false, |n| n.is_perfect_square())
+    }
+
+    /// The binomial coefficient C(n, k), zero for k > n
+    pub fn binomial(n: u64, k: u64) -> NonSmallInt {
+        if k > n {
+            return NonSmallInt::of(0);
         }
+        let k = min(k, n - k);
+        // Each prefix product is itself a binomial coefficient, so every division is exact
+        (0..k).fold(NonSmallInt::of(1), |acc, i| {
+            (&acc * (n - i)).div_exact(&NonSmallInt::of(i + 1)).expect("Binomial prefix products are exact")
+        })
+    }
 
-        fn div_operator(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
-            if y.n != 0 {
-                NonSmallInt::of(x.n / y.n) == (&x.nsi / &y.nsi)
-            } else {
-                true
+    /// The nth Catalan number, C(2n, n) / (n + 1)
+    pub fn catalan(n: u64) -> NonSmallInt {
+        let two_n = n.checked_mul(2).expect("Catalan index is too large");
+        NonSmallInt::binomial(two_n, n).div_exact(&NonSmallInt::of(n).succ()).expect("C(2n, n) is always a multiple of n + 1")
+    }
+
+    /// Repeatedly sums the digits in the given radix until a single digit remains.
+    ///
+    /// # Panics
+    ///
+    /// Panics if radix is outside 2 to 256, as digits_radix does.
+    pub fn digital_root_radix(&self, radix: u32) -> u32 {
+        let mut n: u64 = self.digits_radix(radix).iter().map(|&d| d as u64).sum();
+        while n >= radix as u64 {
+            let mut sum = 0;
+            while n > 0 {
+                sum += n % radix as u64;
+                n /= radix as u64;
             }
+            n = sum;
         }
+        n as u32
+    }
 
-        fn rem_operator(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
-            if y.n != 0 {
-                NonSmallInt::of(x.n % y.n) == (&x.nsi % &y.nsi)
-            } else {
-                true
+    /// n! % modulus, reducing after every multiplication
+    pub fn factorial_mod(n: u64, modulus: &NonSmallInt) -> NonSmallInt {
+        let mut acc = &NonSmallInt::of(1) % modulus;
+        for i in 2..n+1 {
+            if acc.is_zero() {
+                break;
             }
+            acc = &(&acc * i) % modulus;
         }
+        acc
+    }
 
-        fn add_operator(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
-            let lhs = NonSmallInt::of(x.n + y.n);
-            let rhs = &x.nsi + &y.nsi;
-            lhs == rhs
+    /// The Jacobi symbol (self / n), which is 1, -1 or 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if n is even or zero.
+    pub fn jacobi_symbol(&self, n: &NonSmallInt) -> i8 {
+        if n.rem_u32(2) == 0 {
+            panic!("The Jacobi symbol is only defined for odd positive n")
+        }
+        let mut a = self % n;
+        let mut n = n.clone();
+        let mut out = 1;
+        while !a.is_zero() {
+            while a.rem_u32(2) == 0 {
+                a = &a / 2u32;
+                let r = n.rem_u32(8);
+                if r == 3 || r == 5 {
+                    out = -out;
+                }
+            }
+            // Quadratic reciprocity
+            mem::swap(&mut a, &mut n);
+            if a.rem_u32(4) == 3 && n.rem_u32(4) == 3 {
+                out = -out;
+            }
+            a = &a % &n;
         }
+        if n == NonSmallInt::of(1) {
+            out
+        } else {
+            0
+        }
+    }
 
-        fn mul_operator(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
-            let lhs = NonSmallInt::of(x.n * y.n);
-            let rhs = x.nsi * y.nsi;
-            lhs == rhs
+    /// English words for the value, e.g. "one thousand two hundred thirty-four", or "number too
+    /// large" beyond the vigintillions (more than 66 digits)
+    pub fn to_english_words(&self) -> String {
+        const ONES: [&str; 20] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+            "ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen"];
+        const TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+        const SCALES: [&str; 22] = ["", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion",
+            "sextillion", "septillion", "octillion", "nonillion", "decillion", "undecillion", "duodecillion",
+            "tredecillion", "quattuordecillion", "quindecillion", "sexdecillion", "septendecillion", "octodecillion",
+            "novemdecillion", "vigintillion"];
+
+        let digits = self.significant_digits();
+        if digits.is_empty() {
+            return ONES[0].to_string();
+        }
+        let groups: Vec<&[u8]> = digits.chunks(3).collect();
+        if groups.len() > SCALES.len() {
+            return "number too large".to_string();
         }
 
-        fn power(x: MinimalNonSmallInt, y: SmallInt) -> bool {
-            x.nsi.pow(y.n as u32) == NonSmallInt::of(x.n.pow(y.n as u32))
+        let mut words: Vec<String> = Vec::new();
+        for (group, scale) in groups.iter().zip(SCALES.iter()).rev() {
+            let hundreds = group.get(2).cloned().unwrap_or(0) as usize;
+            let tens = group.get(1).cloned().unwrap_or(0) as usize;
+            let ones = group[0] as usize;
+            if hundreds != 0 {
+                words.push(format!("{} hundred", ONES[hundreds]));
+            }
+            if tens >= 2 && ones != 0 {
+                words.push(format!("{}-{}", TENS[tens], ONES[ones]));
+            } else if tens >= 2 {
+                words.push(TENS[tens].to_string());
+            } else if tens != 0 || ones != 0 {
+                words.push(ONES[tens * 10 + ones].to_string());
+            }
+            if (hundreds != 0 || tens != 0 || ones != 0) && !scale.is_empty() {
+                words.push(scale.to_string());
+            }
         }
+        words.join(" ")
+    }
 
-        fn sum(xs: Vec<MinimalNonSmallInt>) -> bool {
-            let smallsies: Vec<u64> = xs.iter().map(|n| n.n).collect();
-            let bigsies: Vec<NonSmallInt> = xs.into_iter().map(|n| n.nsi).collect();
+    /// Number of bits in the binary representation, zero for zero
+    pub fn bit_length(&self) -> u64 {
+        self.digits_radix(2).len() as u64
+    }
 
-            NonSmallInt::of(smallsies.iter().sum()) == bigsies.into_iter().sum()
+    /// Floor of the nth root by Newton's method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if n is zero.
+    pub fn nth_root(&self, n: u32) -> NonSmallInt {
+        if n == 0 {
+            panic!("The zeroth root is undefined")
+        } else if n == 1 || self.is_zero() {
+            return self.clone();
+        }
+        // RADIX^ceil(length / n) is never below the root, so the iteration decreases to it
+        let mut x = NonSmallInt::of(1).times_radix((self.length(RADIX) + n as usize - 1) / n as usize);
+        loop {
+            let y = &(&(&x * (n - 1)) + &(self / &x.pow(n - 1))) / n;
+            if y >= x {
+                return x;
+            }
+            x = y;
         }
     }
 
-    #[test]
-    fn double_sided_iter_digits() {
-        let nsi = NonSmallInt::of(654321);
-        let mut iter = nsi.iter_digits(10);
+    /// Some (b, e) with e >= 2 and b^e == self, trying exponents upwards from 2 so that e is the
+    /// smallest such exponent (64 gives (8, 2)), or None if self isn't a perfect power
+    pub fn is_perfect_power(&self) -> Option<(NonSmallInt, u32)> {
+        if *self <= NonSmallInt::of(1) {
+            return Some((self.clone(), 2));
+        }
+        (2..self.bit_length() as u32 + 1)
+            .map(|e| (self.nth_root(e), e))
+            .find(|&(ref b, e)| b.pow(e) == *self)
+    }
 
+    /// Euler's totient by counting 1 <= k <= self with gcd(k, self) == 1, or None when self
+    /// exceeds limit to keep the count feasible
+    pub fn totient_bruteforce_bound(&self, limit: u64) -> Option<NonSmallInt> {
+        if *self > NonSmallInt::of(limit) {
+            return None;
+        }
+        let one = NonSmallInt::of(1);
+        let mut count = NonSmallInt::of(0);
+        let mut k = one.clone();
+        while k <= *self {
+            if k.gcd(self) == one {
+                count.add_in_place(&one);
+            }
+            k.add_in_place(&one);
+        }
+        Some(count)
+    }
+
+    /// Sum of the divisors of self below self by trial division up to its square root, or None
+    /// when self is zero or exceeds limit
+    pub fn aliquot_sum(&self, limit: u64) -> Option<NonSmallInt> {
+        if self.is_zero() || *self > NonSmallInt::of(limit) {
+            return None;
+        }
+        let n = self.to_u64_saturating();
+        let root = self.isqrt().to_u64_saturating();
+        let mut sum = 0u128;
+        for d in 1..root+1 {
+            if n % d == 0 {
+                let pair = n / d;
+                sum += d as u128;
+                if pair != d {
+                    sum += pair as u128;
+                }
+            }
+        }
+        Some(NonSmallInt::of_u128(sum - n as u128))
+    }
+
+    /// Whether self and other are distinct and each is the aliquot sum of the other, computing
+    /// the aliquot sums under the given limit
+    pub fn is_amicable_with(&self, other: &NonSmallInt, limit: u64) -> bool {
+        self != other &&
+            self.aliquot_sum(limit).as_ref() == Some(other) &&
+            other.aliquot_sum(limit).as_ref() == Some(self)
+    }
+
+    /// A uniformly random value in [low, high], by rejection sampling on random digit strings
+    /// as long as high - low.
+    ///
+    /// # Panics
+    ///
+    /// Panics if low is greater than high.
+    #[cfg(feature = "rand")]
+    pub fn random_range<R: rand::Rng>(rng: &mut R, low: &NonSmallInt, high: &NonSmallInt) -> NonSmallInt {
+        let span = high.safe_sub(low).expect("Empty range: low is greater than high");
+        let length = span.length(RADIX);
+        // Each candidate is accepted with probability over 1/RADIX
+        loop {
+            let digits: Vec<u8> = (0..length).map(|_| rng.gen_range(0, RADIX as u8)).collect();
+            let candidate = NonSmallInt { digits };
+            if candidate <= span {
+                return low + &candidate;
+            }
+        }
+    }
+
+    /// Length of the repeating block of the decimal expansion of 1/n, zero if it terminates, as the
+    /// multiplicative order of 10 modulo n without its factors of 2 and 5; None for a zero n or a
+    /// period over limit
+    pub fn decimal_period(n: &NonSmallInt, limit: u64) -> Option<u64> {
+        if n.is_zero() {
+            return None;
+        }
+        let mut m = n.clone();
+        for &p in [2, 5].iter() {
+            loop {
+                let (q, r) = m.div_rem_u32(p);
+                if r != 0 {
+                    break;
+                }
+                m = q;
+            }
+        }
+        let one = NonSmallInt::of(1);
+        if m == one {
+            return Some(0);
+        }
+        let mut remainder = &NonSmallInt::of(RADIX) % &m;
+        let mut period = 1;
+        while remainder != one {
+            if period >= limit {
+                return None;
+            }
+            remainder = &(&remainder * RADIX as u32) % &m;
+            period += 1;
+        }
+        if period > limit {
+            None
+        } else {
+            Some(period)
+        }
+    }
+
+    /// a + (a + 1) + ... + b, as triangular(b) - triangular(a - 1), or None if a > b
+    pub fn range_sum(a: &NonSmallInt, b: &NonSmallInt) -> Option<NonSmallInt> {
+        if a > b {
+            return None;
+        }
+        let below = match a.safe_sub(&NonSmallInt::of(1)) {
+            Some(a_minus_one) => NonSmallInt::triangular(&a_minus_one),
+            None => NonSmallInt::of(0)
+        };
+        NonSmallInt::triangular(b).safe_sub(&below)
+    }
+
+    /// self % 97, carrying a running remainder over seven digits at a time as in IBAN validation
+    pub fn mod97(&self) -> u32 {
+        const CHUNK_SIZE: usize = 7;
+        self.significant_digits().rchunks(CHUNK_SIZE).fold(0u64, |r, chunk| {
+            let value = chunk.iter().rev().fold(0u64, |acc, &d| acc * RADIX + d as u64);
+            (r * RADIX.pow(chunk.len() as u32) + value) % 97
+        }) as u32
+    }
+
+    /// Whether the lowest digit is even
+    pub fn is_even(&self) -> bool {
+        self.digits.first().cloned().unwrap_or(0) % 2 == 0
+    }
+
+    /// Greatest common divisor by Stein's algorithm, trading long division for halving and subtraction
+    pub fn binary_gcd(&self, other: &NonSmallInt) -> NonSmallInt {
+        if self.is_zero() {
+            return other.clone();
+        } else if other.is_zero() {
+            return self.clone();
+        }
+        let (mut a, mut b) = (self.clone(), other.clone());
+        let mut shift = 0;
+        while a.is_even() && b.is_even() {
+            a = a.halve();
+            b = b.halve();
+            shift += 1;
+        }
+        while a.is_even() {
+            a = a.halve();
+        }
+        while !b.is_zero() {
+            while b.is_even() {
+                b = b.halve();
+            }
+            if b < a {
+                mem::swap(&mut a, &mut b);
+            }
+            b = b.safe_sub(&a).unwrap();
+        }
+        (0..shift).fold(a, |acc, _| acc.double())
+    }
+
+    /// self^(2^times), squaring times times
+    pub fn repeated_square(&self, times: u32) -> NonSmallInt {
+        (0..times).fold(self.clone(), |acc, _| acc.square())
+    }
+
+    /// Number of differing bits between the base-2 expansions of self and other
+    pub fn binary_hamming_distance(&self, other: &NonSmallInt) -> u64 {
+        let (lhs, rhs) = (self.digits_radix(2), other.digits_radix(2));
+        let length = max(lhs.len(), rhs.len());
+        let bit = |bits: &[u8], ix: usize| bits.get(ix).cloned().unwrap_or(0);
+        (0..length).filter(|&ix| bit(&lhs, ix) != bit(&rhs, ix)).count() as u64
+    }
+
+    /// Quotient, or DivError::DivisionByZero for a zero rhs
+    pub fn checked_div(&self, rhs: &NonSmallInt) -> Result<NonSmallInt, DivError> {
+        self.div_nsi(rhs).map(|(q, _)| q).ok_or(DivError::DivisionByZero)
+    }
+
+    /// Remainder, or DivError::DivisionByZero for a zero rhs
+    pub fn checked_rem(&self, rhs: &NonSmallInt) -> Result<NonSmallInt, DivError> {
+        self.div_nsi(rhs).map(|(_, r)| r).ok_or(DivError::DivisionByZero)
+    }
+
+    /// Sum of pairwise products of a and b, or None if their lengths differ
+    pub fn dot_product(a: &[NonSmallInt], b: &[NonSmallInt]) -> Option<NonSmallInt> {
+        if a.len() != b.len() {
+            return None;
+        }
+        let mut sum = NonSmallInt::of(0);
+        for (x, y) in a.iter().zip(b) {
+            sum.add_in_place(&(x * y));
+        }
+        Some(sum)
+    }
+
+    /// C(n, k) mod the prime p by Lucas' theorem over the base-p digits of n and k.
+    ///
+    /// # Panics
+    ///
+    /// Panics if p is below 2.
+    pub fn binomial_mod_prime(n: &NonSmallInt, k: &NonSmallInt, p: u32) -> u32 {
+        if p < 2 {
+            panic!("{} is not a prime", p)
+        }
+        fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+            let mut out = 1;
+            while exp > 0 {
+                if exp % 2 == 1 {
+                    out = out * base % modulus;
+                }
+                base = base * base % modulus;
+                exp /= 2;
+            }
+            out
+        }
+        fn small_binomial_mod(n: u64, k: u64, p: u64) -> u64 {
+            if k > n {
+                return 0;
+            }
+            let k = min(k, n - k);
+            let numerator = (0..k).fold(1, |acc, i| acc * (n - i) % p);
+            let denominator = (1..=k).fold(1, |acc, i| acc * i % p);
+            numerator * pow_mod(denominator, p - 2, p) % p
+        }
+        let p = p as u64;
+        let (n_digits, k_digits) = (n.digits_radix_wide(p as u32), k.digits_radix_wide(p as u32));
+        let digit = |digits: &[u32], ix: usize| digits.get(ix).cloned().unwrap_or(0) as u64;
+        (0..max(n_digits.len(), k_digits.len())).fold(1 % p, |acc, ix| {
+            acc * small_binomial_mod(digit(&n_digits, ix), digit(&k_digits, ix), p) % p
+        }) as u32
+    }
+
+    /// Number of carries when adding self and other in base 10
+    pub fn carries_when_adding(&self, other: &NonSmallInt) -> u32 {
+        let length = max(self.digits.len(), other.digits.len());
+        let mut carry = 0;
+        let mut carries = 0;
+        for (l, r) in self.iter_digits(length).zip(other.iter_digits(length)) {
+            carry = (l + r + carry) / RADIX as u8;
+            carries += carry as u32;
+        }
+        carries
+    }
+
+    /// Exponent of the prime p in n! by Legendre's formula, summing floor(n / p^i)
+    pub fn factorial_prime_valuation(n: u64, p: u32) -> u64 {
+        if p < 2 {
+            panic!("{} is not a prime", p)
+        }
+        let mut exponent = 0;
+        let mut q = n;
+        while q > 0 {
+            q /= p as u64;
+            exponent += q;
+        }
+        exponent
+    }
+
+    /// Divides out every copy of factor, returning the rest and the number of copies removed.
+    /// Zero is returned as is with a count of 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if factor is 0 or 1.
+    pub fn remove_factor(&self, factor: &NonSmallInt) -> (NonSmallInt, u32) {
+        if *factor <= NonSmallInt::of(1) {
+            panic!("Cannot remove a factor of {}", factor)
+        }
+        let mut n = self.clone();
+        let mut count = 0;
+        if n.is_zero() {
+            return (n, count);
+        }
+        while let Some(q) = n.div_exact(factor) {
+            n = q;
+            count += 1;
+        }
+        (n, count)
+    }
+
+    /// Floor of the cube root, by a Newton iteration seeded from the leading digits
+    pub fn icbrt(&self) -> NonSmallInt {
+        let digits = self.significant_digits();
+        if digits.is_empty() {
+            return NonSmallInt::of(0);
+        }
+        // The leading one to three digits v, split off so a whole number of digit triples remains,
+        // seed the iteration with the smallest c where c^3 > v, scaled by the triples
+        let lead = (digits.len() - 1) % 3 + 1;
+        let v = digits.iter().rev().take(lead).fold(0u64, |acc, &d| acc * RADIX + d as u64);
+        let c = (1..).find(|c: &u64| c * c * c > v).unwrap();
+        let mut x = NonSmallInt::of(c).times_radix((digits.len() - lead) / 3);
+        loop {
+            let y = &(&(&x * 2u32) + &(self / &x.square())) / 3u32;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Replaces every decimal digit d with f(d) % 10, keeping its position, with zero as the
+    /// single digit 0
+    pub fn map_digits(&self, f: impl Fn(u8) -> u8) -> NonSmallInt {
+        let digits = match self.significant_digits() {
+            [] => &[0][..],
+            digits => digits
+        };
+        NonSmallInt::from_le_digits(digits.iter().map(|&d| f(d) % RADIX as u8).collect())
+    }
+
+    /// Length of the shortest repunit 11...1 that self divides, trying lengths up to max_len, or
+    /// None if there is none in range (always so for zero and multiples of 2 or 5)
+    pub fn smallest_repunit_multiple(&self, max_len: usize) -> Option<usize> {
+        if self.is_zero() {
+            return None;
+        }
+        let one = NonSmallInt::of(1);
+        let mut residue = NonSmallInt::of(0);
+        for len in 1..=max_len {
+            residue = &(&(&residue * 10u32) + &one) % self;
+            if residue.is_zero() {
+                return Some(len);
+            }
+        }
+        None
+    }
+
+    /// Every (start, count) with 2 <= count <= limit and start + (start + 1) + ... + (start + count - 1)
+    /// equal to self for a positive start, in increasing order of count
+    pub fn consecutive_sum_representations(&self, limit: u64) -> Vec<(NonSmallInt, u64)> {
+        let mut out = Vec::new();
+        for count in 2..=limit {
+            // self == count * start + T(count - 1) for the triangular number T
+            let offset = NonSmallInt::triangular(&NonSmallInt::of(count - 1));
+            match self.checked_sub(&offset) {
+                Some(ref rest) if *rest >= NonSmallInt::of(count) => {
+                    if let Some(start) = rest.div_exact(&NonSmallInt::of(count)) {
+                        out.push((start, count));
+                    }
+                }
+                _ => break
+            }
+        }
+        out
+    }
+
+    /// The Verhoeff check digit to append to the decimal digits of self
+    pub fn verhoeff_check_digit(&self) -> u8 {
+        // The appended check digit takes position 0, so the lowest digit of self sits at position 1
+        let c = self.significant_digits().iter().enumerate().fold(0, |c, (ix, &d)| {
+            VERHOEFF_D[c as usize][VERHOEFF_P[(ix + 1) % 8][d as usize] as usize]
+        });
+        VERHOEFF_INV[c as usize]
+    }
+
+    /// Number of consecutive 9 digits at the least significant end, which a +1 carry runs through
+    pub fn trailing_nines(&self) -> usize {
+        self.digits.iter().take_while(|&&d| d == 9).count()
+    }
+
+    /// Sum of the first n terms of the sequence starting with initial and continuing with
+    /// x_k = coeffs[0] * x_(k-1) + coeffs[1] * x_(k-2) + ...
+    ///
+    /// # Panics
+    ///
+    /// Panics if more terms are needed than initial gives and it has fewer values than coeffs.
+    pub fn linear_recurrence_sum(coeffs: &[u64], initial: &[NonSmallInt], n: u64) -> NonSmallInt {
+        let mut sum = NonSmallInt::of(0);
+        for x in initial.iter().take(n as usize) {
+            sum.add_in_place(x);
+        }
+        if n as usize <= initial.len() {
+            return sum;
+        } else if initial.len() < coeffs.len() {
+            panic!("{} initial values cannot seed a recurrence of order {}", initial.len(), coeffs.len())
+        }
+        // The most recent coeffs.len() terms, oldest first
+        let mut window: Vec<NonSmallInt> = initial[initial.len() - coeffs.len()..].to_vec();
+        for _ in initial.len() as u64..n {
+            let next = coeffs.iter().zip(window.iter().rev()).fold(NonSmallInt::of(0), |acc, (&c, x)| acc + x * c);
+            sum.add_in_place(&next);
+            if !window.is_empty() {
+                window.remove(0);
+                window.push(next);
+            }
+        }
+        sum
+    }
+
+    /// The number left by removing the count most significant digits
+    pub fn drop_leading(&self, count: usize) -> NonSmallInt {
+        let digits = self.significant_digits();
+        NonSmallInt::from_le_digits(digits[..digits.len().saturating_sub(count)].to_vec())
+    }
+
+    /// Whether self and other have the same significant decimal digits in some order
+    pub fn is_digit_anagram(&self, other: &NonSmallInt) -> bool {
+        self.digit_counts() == other.digit_counts()
+    }
+
+    /// Whether the decimal digits are 1 to digits, each used exactly once, for digits from 1 to 9
+    pub fn is_pandigital(&self, digits: usize) -> bool {
+        let counts = self.digit_counts();
+        (1..=9).contains(&digits) && counts.iter().enumerate().all(|(d, &c)| c == (1 <= d && d <= digits) as u32)
+    }
+
+    /// Whether the decimal digits are 0 to 9, each used exactly once
+    pub fn is_pandigital_0_to_9(&self) -> bool {
+        self.digit_counts() == [1; 10]
+    }
+
+    /// Smallest number written with exactly counts[d] copies of each decimal digit d, or None if
+    /// no number is (no digits at all, or several zeros with nothing else)
+    pub fn smallest_with_digit_counts(counts: &[u32; 10]) -> Option<NonSmallInt> {
+        let lead = match (1..10).find(|&d| counts[d] > 0) {
+            Some(d) => d,
+            None if counts[0] == 1 => return Some(NonSmallInt::of(0)),
+            None => return None
+        };
+        // The smallest non-zero digit leads, then the rest ascending, zeros included
+        let mut msf = vec![lead as u8];
+        for (d, &count) in counts.iter().enumerate() {
+            let count = if d == lead { count - 1 } else { count };
+            let length = msf.len() + count as usize;
+            msf.resize(length, d as u8);
+        }
+        msf.reverse();
+        Some(NonSmallInt { digits: msf })
+    }
+
+    /// The significant digits rearranged from largest to smallest
+    pub fn digits_descending(&self) -> NonSmallInt {
+        // Sorted little-endian digits put the largest ones at the top
+        let mut digits = self.significant_digits().to_vec();
+        digits.sort();
+        NonSmallInt { digits }
+    }
+
+    /// The significant digits rearranged from smallest to largest, so any zeros vanish
+    pub fn digits_ascending(&self) -> NonSmallInt {
+        let mut digits = self.significant_digits().to_vec();
+        digits.sort_by(|a, b| b.cmp(a));
+        NonSmallInt::from_le_digits(digits)
+    }
+
+    /// Differences between adjacent significant digits, most significant first, so each entry is a
+    /// digit minus the one before it
+    pub fn digit_differences(&self) -> Vec<i8> {
+        let digits = self.significant_digits();
+        digits.windows(2).rev().map(|pair| pair[0] as i8 - pair[1] as i8).collect()
+    }
+
+    /// Fingerprint sum of digit_i * base^i mod modulus, where digit_i is the decimal digit i places
+    /// from the right, evaluated by Horner's method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if modulus is zero.
+    pub fn polynomial_hash(&self, base: u32, modulus: u32) -> u32 {
+        assert!(modulus > 0, "Modulus must be positive");
+        let (base, modulus) = (base as u64, modulus as u64);
+        self.significant_digits().iter().rev().fold(0, |h, &d| (h * base + d as u64) % modulus) as u32
+    }
+
+    /// self % 11 without division, since 10 = -1 (mod 11) makes it the alternating digit sum
+    pub fn mod11(&self) -> u32 {
+        self.digits.iter().enumerate().fold(0, |acc, (ix, &d)| {
+            let d = d as u32;
+            if ix % 2 == 0 { (acc + d) % 11 } else { (acc + 11 - d) % 11 }
+        })
+    }
+
+    /// Bitwise AND of the base-2 expansions
+    pub fn bitand(&self, other: &NonSmallInt) -> NonSmallInt {
+        self.bitwise(other, |l, r| l & r)
+    }
+
+    /// Bitwise OR of the base-2 expansions
+    pub fn bitor(&self, other: &NonSmallInt) -> NonSmallInt {
+        self.bitwise(other, |l, r| l | r)
+    }
+
+    /// Bitwise XOR of the base-2 expansions
+    pub fn bitxor(&self, other: &NonSmallInt) -> NonSmallInt {
+        self.bitwise(other, |l, r| l ^ r)
+    }
+
+    /// Whether bit i of the base-2 expansion is set
+    pub fn test_bit(&self, i: u64) -> bool {
+        self.digits_radix(2).get(i as usize) == Some(&1)
+    }
+
+    /// self with bit i of the base-2 expansion set
+    pub fn set_bit(&self, i: u64) -> NonSmallInt {
+        self.with_bit(i, 1)
+    }
+
+    /// self with bit i of the base-2 expansion cleared
+    pub fn clear_bit(&self, i: u64) -> NonSmallInt {
+        self.with_bit(i, 0)
+    }
+
+    /// floor(log10(self) * 10^scale_digits), from the digit count plus the log of the leading digits.
+    ///
+    /// The fractional part comes from an f64, so only its first 15 digits are computed and any
+    /// further requested digits are zero. The last computed digit may be off by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero.
+    pub fn log10_scaled(&self, scale_digits: usize) -> NonSmallInt {
+        const MAX_FRACTION_DIGITS: usize = 15;
+        let digits = self.significant_digits();
+        if digits.is_empty() {
+            panic!("The logarithm of zero is undefined")
+        }
+        let lead = min(digits.len(), 17);
+        let leading = digits.iter().rev().take(lead).fold(0u64, |acc, &d| acc * RADIX + d as u64);
+        let fraction = (leading as f64).log10() - (lead - 1) as f64;
+        let computed = min(scale_digits, MAX_FRACTION_DIGITS);
+        // Clamping keeps rounding at either end inside [0, 1)
+        let scale = 10u64.pow(computed as u32);
+        let fraction = min((fraction.max(0.0) * scale as f64) as u64, scale - 1);
+        let whole = NonSmallInt::of(digits.len() as u64 - 1).times_radix(scale_digits);
+        &whole + &NonSmallInt::of(fraction).times_radix(scale_digits - computed)
+    }
+
+    /// Number of decimal digits that change from self to self.succ(), counting a new leading digit
+    pub fn increment_digit_churn(&self) -> usize {
+        self.trailing_nines() + 1
+    }
+
+    /// self followed by suffix written as exactly suffix_digits zero-padded decimal digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if suffix needs more than suffix_digits digits.
+    pub fn with_suffix(&self, suffix: u64, suffix_digits: usize) -> NonSmallInt {
+        let suffix = NonSmallInt::of(suffix);
+        if suffix.length(RADIX) > suffix_digits {
+            panic!("{} doesn't fit in {} digits", suffix, suffix_digits)
+        }
+        &self.times_radix(suffix_digits) + &suffix
+    }
+
+    /// Sum of self and its other distinct digit rotations, as rotate_digits gives them, so a
+    /// rotation bringing zeros to the front adds its shorter value (10 adds 10 and 1)
+    pub fn rotation_sum(&self) -> NonSmallInt {
+        let digits = self.significant_digits();
+        // Rotations repeat with the smallest period of the digit sequence
+        let period = (1..=digits.len()).find(|&p| {
+            let mut rotated = digits.to_vec();
+            rotated.rotate_left(p % digits.len());
+            rotated == digits
+        }).unwrap_or(0);
+        (0..period).map(|by| self.rotate_digits(by)).sum()
+    }
+
+    /// Smallest k up to max_len where self divides the k-digit repunit, which is the order of
+    /// 10 modulo 9 * self; a prime p other than 3 is a full-reptend prime when this is p - 1
+    pub fn repunit_divisor_length(&self, max_len: usize) -> Option<usize> {
+        self.smallest_repunit_multiple(max_len)
+    }
+
+    /// The smaller r with r^2 = self (mod prime), by Tonelli-Shanks for an odd prime, or None if
+    /// self is a non-residue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if prime is even.
+    pub fn mod_sqrt(&self, prime: &NonSmallInt) -> Option<NonSmallInt> {
+        if prime.is_even() {
+            panic!("Tonelli-Shanks needs an odd prime modulus")
+        }
+        let a = self % prime;
+        if a.is_zero() {
+            return Some(a);
+        } else if a.jacobi_symbol(prime) != 1 {
+            return None;
+        }
+        let one = NonSmallInt::of(1);
+        let (q, s) = (prime - &one).factor_out_twos();
+        // Any non-residue z generates the 2-power part of the group
+        let z = iter::successors(Some(NonSmallInt::of(2)), |z| Some(z.succ()))
+            .find(|z| z.jacobi_symbol(prime) == -1)
+            .expect("Odd primes have non-residues");
+        let mut m = s;
+        let mut c = z.pow_mod(&q, prime);
+        let mut t = a.pow_mod(&q, prime);
+        let mut r = a.pow_mod(&(&q.succ() / 2u32), prime);
+        while t != one {
+            // The least i with t^(2^i) = 1, which is below m
+            let mut i = 0;
+            let mut t_power = t.clone();
+            while t_power != one {
+                t_power = t_power.mul_mod(&t_power, prime);
+                i += 1;
+            }
+            let b = (0..m - i - 1).fold(c, |b, _| b.mul_mod(&b, prime));
+            m = i;
+            c = b.mul_mod(&b, prime);
+            t = t.mul_mod(&c, prime);
+            r = r.mul_mod(&b, prime);
+        }
+        let other = prime - &r;
+        Some(min(r, other))
+    }
+
+    /// Digits in base -2, least significant first, with no digits for zero
+    pub fn to_negabinary(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        // Dividing by -2 flips the sign each step, so track the magnitude and its sign apart
+        let (mut magnitude, mut negative) = (self.clone(), false);
+        while !magnitude.is_zero() {
+            let r = if magnitude.is_even() { 0 } else { 1 };
+            out.push(r);
+            let r = NonSmallInt::of(r as u64);
+            magnitude = if negative { &magnitude + &r } else { &magnitude - &r }.halve();
+            negative = !negative;
+        }
+        out
+    }
+
+    /// Constructs from base -2 digits, least significant first, or None if a digit isn't 0 or 1 or
+    /// the value is negative
+    pub fn from_negabinary(digits: &[u8]) -> Option<NonSmallInt> {
+        let (mut positive, mut negative) = (NonSmallInt::of(0), NonSmallInt::of(0));
+        let mut power = NonSmallInt::of(1);
+        for (ix, &d) in digits.iter().enumerate() {
+            match d {
+                0 => {}
+                1 if ix % 2 == 0 => positive.add_in_place(&power),
+                1 => negative.add_in_place(&power),
+                _ => return None
+            }
+            power = power.double();
+        }
+        positive.checked_sub(&negative)
+    }
+
+    /// Partial quotients [a0; a1, a2, ...] of num / den from the steps of Euclid's algorithm, with
+    /// none for a zero den
+    pub fn continued_fraction_of_ratio(num: &NonSmallInt, den: &NonSmallInt) -> Vec<NonSmallInt> {
+        let mut out = Vec::new();
+        let (mut a, mut b) = (num.clone(), den.clone());
+        while let Some((q, r)) = a.div_nsi(&b) {
+            out.push(q);
+            a = b;
+            b = r;
+        }
+        out
+    }
+
+    /// The fraction closest to num / den among those with a denominator of at most max_den, as
+    /// (numerator, denominator) in lowest terms. It is either the last convergent of the continued
+    /// fraction within the bound or a semiconvergent past it, the convergent winning ties.
+    ///
+    /// # Panics
+    ///
+    /// Panics if den or max_den is zero.
+    pub fn best_rational_approx(num: &NonSmallInt, den: &NonSmallInt, max_den: &NonSmallInt) -> (NonSmallInt, NonSmallInt) {
+        if den.is_zero() || max_den.is_zero() {
+            panic!("Denominators must be positive")
+        }
+        let (mut p0, mut q0) = (NonSmallInt::of(0), NonSmallInt::of(1));
+        let (mut p1, mut q1) = (NonSmallInt::of(1), NonSmallInt::of(0));
+        let (mut n, mut d) = (num.clone(), den.clone());
+        while let Some((a, r)) = n.div_nsi(&d) {
+            let q2 = &q0 + &(&a * &q1);
+            if q2 > *max_den {
+                break;
+            }
+            let p2 = &p0 + &(&a * &p1);
+            p0 = mem::replace(&mut p1, p2);
+            q0 = mem::replace(&mut q1, q2);
+            n = mem::replace(&mut d, r);
+        }
+        if d.is_zero() {
+            return (p1, q1);
+        }
+        // The semiconvergent with the largest denominator still within the bound
+        let k = &(max_den - &q0) / &q1;
+        let p_semi = &p0 + &(&k * &p1);
+        let q_semi = &q0 + &(&k * &q1);
+        // |p / q - num / den| scaled by den, as a fraction with q in the denominator
+        let error = |p: &NonSmallInt, q: &NonSmallInt| {
+            let (l, r) = (p * den, num * q);
+            if l > r { &l - &r } else { &r - &l }
+        };
+        if &error(&p1, &q1) * &q_semi <= &error(&p_semi, &q_semi) * &q1 {
+            (p1, q1)
+        } else {
+            (p_semi, q_semi)
+        }
+    }
+
+    /// Sum of digit_i * (i + 1) mod modulus, where digit_i is the decimal digit i places from the
+    /// right, so that swapping two different digits changes the sum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if modulus is zero.
+    pub fn positional_checksum(&self, modulus: u32) -> u32 {
+        assert!(modulus > 0, "Modulus must be positive");
+        let modulus = modulus as u64;
+        self.significant_digits().iter().zip(1..).fold(0, |acc, (&d, weight): (&u8, u64)| {
+            (acc + d as u64 * (weight % modulus)) % modulus
+        }) as u32
+    }
+
+    /// (d, s) with d odd and self == d * 2^s, or (0, 0) for zero
+    pub fn factor_out_twos(&self) -> (NonSmallInt, u32) {
+        let mut d = self.clone();
+        let mut s = 0;
+        if d.is_zero() {
+            return (d, s);
+        }
+        while d.is_even() {
+            d = d.halve();
+            s += 1;
+        }
+        (d, s)
+    }
+
+    /// All divisors of self in increasing order by trial division up to its square root, or None
+    /// when self is zero or exceeds limit
+    pub fn divisors(&self, limit: u64) -> Option<Vec<NonSmallInt>> {
+        if self.is_zero() || *self > NonSmallInt::of(limit) {
+            return None;
+        }
+        let n = self.to_u64_saturating();
+        let root = self.isqrt().to_u64_saturating();
+        let (mut small, mut large) = (Vec::new(), Vec::new());
+        for d in 1..root+1 {
+            if n % d == 0 {
+                small.push(NonSmallInt::of(d));
+                if n / d != d {
+                    large.push(NonSmallInt::of(n / d));
+                }
+            }
+        }
+        small.extend(large.into_iter().rev());
+        Some(small)
+    }
+
+    /// Returns (quotient, remainder)
+    fn div_u32(&self, rhs: u32) -> Option<(NonSmallInt, NonSmallInt)> {
+        if rhs == 0 {
+            None
+        } else {
+            let mut quotient = Vec::new();
+            let mut carry = 0u64;
+            for digit in self.digits.iter().rev() {
+                let temp: u64 = carry * RADIX + (*digit as u64);
+                let out: u8 = (temp / rhs as u64) as u8;
+                carry = temp % (rhs as u64);
+                quotient.insert(0, out);
+            }
+            let mut remainder = Vec::new();
+            while carry > 0 {
+                let out = carry % RADIX;
+                carry /= RADIX;
+                remainder.push(out as u8);
+            }
+            Some((NonSmallInt { digits: quotient }, NonSmallInt { digits: remainder }))
+        }
+    }
+
+    fn halve(&self) -> NonSmallInt {
+        self.div_rem_u32(2).0
+    }
+
+    fn double(&self) -> NonSmallInt {
+        self * 2u32
+    }
+
+    /// Digits in any radix from 2, least significant first, with no digits for zero
+    fn digits_radix_wide(&self, radix: u32) -> Vec<u32> {
+        let mut out = Vec::new();
+        let mut n = self.clone();
+        while !n.is_zero() {
+            let (q, r) = n.div_rem_u32(radix);
+            out.push(r);
+            n = q;
+        }
+        out
+    }
+
+    /// Number of times each decimal digit occurs among the significant digits
+    fn digit_counts(&self) -> [u32; 10] {
+        let mut counts = [0; 10];
+        for &d in self.significant_digits() {
+            counts[d as usize] += 1;
+        }
+        counts
+    }
+
+    /// Combines the base-2 expansions bit by bit, with the shorter one zero-padded
+    fn bitwise<F: Fn(u8, u8) -> u8>(&self, other: &NonSmallInt, op: F) -> NonSmallInt {
+        let (lhs, rhs) = (self.digits_radix(2), other.digits_radix(2));
+        let bit = |bits: &[u8], ix: usize| bits.get(ix).cloned().unwrap_or(0);
+        let bits: Vec<u8> = (0..max(lhs.len(), rhs.len())).rev().map(|ix| op(bit(&lhs, ix), bit(&rhs, ix))).collect();
+        NonSmallInt::from_digits_radix(&bits, 2).expect("Bits are valid base-2 digits")
+    }
+
+    fn with_bit(&self, i: u64, value: u8) -> NonSmallInt {
+        let mut bits = self.digits_radix(2);
+        if bits.len() <= i as usize {
+            bits.resize(i as usize + 1, 0);
+        }
+        bits[i as usize] = value;
+        bits.reverse();
+        NonSmallInt::from_digits_radix(&bits, 2).expect("Bits are valid base-2 digits")
+    }
+
+    fn of_u128(mut n: u128) -> NonSmallInt {
+        let mut digits = Vec::new();
+        while n > 0 {
+            digits.push((n % RADIX as u128) as u8);
+            n /= RADIX as u128;
+        }
+        NonSmallInt { digits }
+    }
+
+    /// Constructs from little-endian digits, dropping the leading zeros
+    fn from_le_digits(mut digits: Vec<u8>) -> NonSmallInt {
+        let length = digits.iter().rposition(|&d| d != 0).map_or(0, |ix| ix + 1);
+        digits.truncate(length);
+        NonSmallInt { digits }
+    }
+
+    /// Returns (quotient without leading zeros, remainder) for a non-zero rhs
+    fn div_rem_u32(&self, rhs: u32) -> (NonSmallInt, u32) {
+        let mut quotient = Vec::with_capacity(self.digits.len());
+        let mut carry = 0u64;
+        for digit in self.significant_digits().iter().rev() {
+            let temp: u64 = carry * RADIX + (*digit as u64);
+            quotient.push((temp / rhs as u64) as u8);
+            carry = temp % (rhs as u64);
+        }
+        quotient.reverse();
+        (NonSmallInt::from_le_digits(quotient), carry as u32)
+    }
+
+    fn div_nsi(&self, rhs: &NonSmallInt) -> Option<(NonSmallInt, NonSmallInt)> {
+        if rhs.is_zero() {
+            None
+        } else if rhs.length(RADIX) == 1 {
+            self.div_u32(rhs.digits[0] as u32)
+        } else if self.length(RADIX) < rhs.length(RADIX) {
+            Some((NonSmallInt { digits: vec![] }, self.clone()))
+        } else {
+            long_division(self, rhs)
+        }
+    }
+
+    fn lt(&self, rhs: &NonSmallInt) -> bool {
+        if self.length(RADIX) < rhs.length(RADIX) {
+            true
+        } else {
+            let max_length = max(self.digits.len(), rhs.digits.len());
+            let lhs_digits = self.iter_digits(max_length).rev();
+            let rhs_digits = rhs.iter_digits(max_length).rev();
+            match lhs_digits.zip(rhs_digits).find(|&(lhs_d, rhs_d)| lhs_d != rhs_d) {
+                None => false,
+                Some((lhs_d, rhs_d)) => lhs_d < rhs_d
+            }
+        }
+    }
+
+    /// Result or None for underflow
+    fn safe_sub(&self, rhs: &NonSmallInt) -> Option<NonSmallInt> {
+        let mut out = Vec::new();
+        let mut borrow = 0u32;
+        let max_length = max(self.digits.len(), rhs.digits.len());
+        for (l, r) in self.iter_digits(max_length).zip(rhs.iter_digits(max_length)) {
+            let diff: u32 = (RADIX as u32 + l as u32).wrapping_sub(r as u32 + borrow);
+            out.push((diff % RADIX as u32) as u8);
+            borrow = 1 - diff / RADIX as u32;
+        }
+        if borrow == 0 {
+            Some(NonSmallInt { digits: out })
+        } else {
+            None
+        }
+    }
+
+    fn iter_digits(&self, length: usize) -> Digits<'_> {
+        Digits { nsi: self, next_ix: 0, next_back_ix: length as isize - 1, empty: length == 0 }
+    }
+}
+
+struct Digits<'a> { nsi: &'a NonSmallInt, next_ix: usize, next_back_ix: isize, empty: bool }
+
+impl <'a> Iterator for Digits<'a> {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {
+        let next_value = |d: &mut Digits| {
+            let out = if d.next_ix < d.nsi.digits.len() { d.nsi.digits[d.next_ix] } else { 0 };
+            d.next_ix += 1;
+            out
+        };
+        if !self.empty && self.next_ix <= self.next_back_ix as usize {
+            Some(next_value(self))
+        } else {
+            None
+        }
+    }
+}
+
+impl <'a> DoubleEndedIterator for Digits<'a> {
+    fn next_back(&mut self) -> Option<u8> {
+        let next_value = |d: &mut Digits| {
+            let out = if (d.next_back_ix as usize) < d.nsi.digits.len() { d.nsi.digits[d.next_back_ix as usize] } else { 0 };
+            d.next_back_ix -= 1;
+            out
+        };
+        if !self.empty && self.next_back_ix >= (self.next_ix as isize) {
+            Some(next_value(self))
+        } else {
+            None
+        }
+    }
+}
+
+/// Implementation from http://surface.syr.edu/cgi/viewcontent.cgi?article=1162&context=eecs_techreports
+/// Requires 2 <= rhs.length() <= lhs.length()
+fn long_division(lhs: &NonSmallInt, rhs: &NonSmallInt) -> Option<(NonSmallInt, NonSmallInt)> {
+
+    if rhs.is_zero() {
+        None
+    } else {
+        trait IndexingIsHard<A> {
+            /// Returns default value for A if doesn't exist
+            fn lookup(&self, ix: usize) -> A;
+
+            /// Resizes self if doesn't fit new value
+            fn put(&mut self, ix: usize, value: A);
+        }
+
+        static ZERO: u8 = 0;
+
+        impl IndexingIsHard<u8> for Vec<u8> {
+            fn lookup(&self, ix: usize) -> u8 {
+                *self.get(ix).unwrap_or(&ZERO)
+            }
+            fn put(&mut self, ix: usize, value: u8) {
+                if ix < self.len() {
+                    self[ix] = value;
+                } else {
+                    self.insert(ix, value);
+                }
+            }
+        }
+
+        let trial = |r: &Vec<u8>, d: &Vec<u8>, k: usize, m: usize| -> u8 {
+            let km = k + m;
+            let r3: u64 = ((r.lookup(km) as u64 * RADIX) + r.lookup(km-1) as u64) * RADIX + r.lookup(km-2) as u64;
+            let d2: u64 = d.lookup(m-1) as u64 * RADIX + d.lookup(m-2) as u64;
+            min(r3 / d2, RADIX - 1) as u8
+        };
+
+        let smaller = |r: &Vec<u8>, dq: &Vec<u8>, k: usize, m: usize| -> bool {
+            let mut i = m;
+            let mut j = 0;
+            while i != j {
+                if r.lookup(i+k) != dq.lookup(i) {
+                    j = i;
+                } else {
+                    i -= 1;
+                }
+            }
+            r.lookup(i+k) < dq.lookup(i)
+        };
+
+        let difference = |r: &mut Vec<u8>, dq: &Vec<u8>, k: usize, m: usize| {
+            let mut borrow: u64 = 0;
+            for i in 0..m+1 {
+                let diff: u64 = (RADIX + *r.get(i+k).unwrap_or(&ZERO) as u64).wrapping_sub(*dq.get(i).unwrap_or(&ZERO) as u64 + borrow);
+                r.put(i+k, (diff % RADIX) as u8);
+                borrow = 1 - diff / RADIX;
+            }
+        };
+
+        let longdivide = |x: &NonSmallInt, y: &NonSmallInt| -> (NonSmallInt, NonSmallInt) {
+            let n = x.length(RADIX);
+            let m = y.length(RADIX);
+
+            let f: u8 = RADIX as u8 / (y.digits[m-1] + 1);
+
+            let mut r = x * f as u32;
+            let d = y * f as u32;
+            let mut q = Vec::new();
+
+            for k in (0..(n-m+1)).rev() {
+                let mut qt = trial(&r.digits, &d.digits, k, m);
+                let mut dq = &d * qt as u32;
+                if smaller(&r.digits, &dq.digits, k, m) {
+                    qt -= 1;
+                    dq = &d * qt as u32;
+                }
+                q.insert(0, qt);
+                difference(&mut r.digits, &dq.digits, k, m)
+            }
+
+            r = r.div_u32(f as u32).expect("Division by Zero is not permitted").0;
+
+            (NonSmallInt { digits: q }, r)
+        };
+
+        Some(longdivide(lhs, rhs))
+    }
+}
+
+impl PartialEq for NonSmallInt {
+    fn eq(&self, other: &NonSmallInt) -> bool {
+        self.digits.iter().rev().skip_while(|&n| *n == 0).eq(other.digits.iter().rev().skip_while(|&n| *n == 0))
+    }
+}
+
+impl Div for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn div(self, rhs: &NonSmallInt) -> NonSmallInt {
+        match self.div_nsi(rhs) {
+            None => panic!("Division by zero is not allowed"),
+            Some((q, _)) => q
+        }
+    }
+}
+
+impl Div<u32> for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn div(self, rhs: u32) -> NonSmallInt {
+        match self.div_u32(rhs) {
+            None => panic!("Division by zero is not allowed"),
+            Some((q, _)) => q
+        }
+    }
+}
+
+impl Rem for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn rem(self, rhs: &NonSmallInt) -> NonSmallInt {
+        match self.div_nsi(rhs) {
+            None => panic!("Division by zero is not supported"),
+            Some((_, r)) => r
+        }
+    }
+}
+
+impl Rem<u32> for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn rem(self, rhs: u32) -> NonSmallInt {
+        match self.div_u32(rhs) {
+            None => panic!("Division by zero is not supported"),
+            Some((_, r)) => r
+        }
+    }
+}
+
+impl Mul<u32> for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn mul(self, rhs: u32) -> NonSmallInt {
+        let mut out_digits = Vec::new();
+        let mut carry = 0u64;
+        for digit in self.digits.iter() {
+            let temp: u64 = (rhs as u64) * (*digit as u64) + carry;
+            let out: u8 = (temp % RADIX) as u8;
+            carry = temp / RADIX;
+            out_digits.push(out);
+        }
+        while carry != 0 {
+            let out: u8 = (carry % RADIX) as u8;
+            carry /= RADIX;
+            out_digits.push(out);
+        }
+        NonSmallInt { digits: out_digits }
+    }
+}
+
+impl Mul<u32> for NonSmallInt {
+    type Output = NonSmallInt;
+    fn mul(self, rhs: u32) -> NonSmallInt {
+        (&self).mul(rhs)
+    }
+}
+
+impl Mul<u64> for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn mul(self, rhs: u64) -> NonSmallInt {
+        let mut out_digits = Vec::new();
+        let mut carry = 0u128;
+        for digit in self.digits.iter() {
+            let temp: u128 = (rhs as u128) * (*digit as u128) + carry;
+            out_digits.push((temp % RADIX as u128) as u8);
+            carry = temp / RADIX as u128;
+        }
+        while carry != 0 {
+            out_digits.push((carry % RADIX as u128) as u8);
+            carry /= RADIX as u128;
+        }
+        NonSmallInt { digits: out_digits }
+    }
+}
+
+impl Mul<u64> for NonSmallInt {
+    type Output = NonSmallInt;
+    fn mul(self, rhs: u64) -> NonSmallInt {
+        (&self).mul(rhs)
+    }
+}
+
+impl MulAssign<u32> for NonSmallInt {
+    fn mul_assign(&mut self, rhs: u32) {
+        let mut carry = 0u64;
+        for digit in self.digits.iter_mut() {
+            let temp: u64 = (rhs as u64) * (*digit as u64) + carry;
+            *digit = (temp % RADIX) as u8;
+            carry = temp / RADIX;
+        }
+        while carry != 0 {
+            self.digits.push((carry % RADIX) as u8);
+            carry /= RADIX;
+        }
+    }
+}
+
+impl Mul for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn mul(self, rhs: &NonSmallInt) -> NonSmallInt {
+        let mut out = NonSmallInt::of(0);
+        for (&rhs_d, ix) in rhs.digits.iter().zip(0..) {
+            let to_be_added = (self * (rhs_d as u32)).times_radix(ix);
+            out = out + to_be_added;
+        }
+        out
+    }
+}
+
+impl Mul<NonSmallInt> for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn mul(self, rhs: NonSmallInt) -> NonSmallInt {
+        self.mul(&rhs)
+    }
+}
+
+impl Mul for NonSmallInt {
+    type Output = NonSmallInt;
+    fn mul(self, rhs: NonSmallInt) -> NonSmallInt {
+        (&self).mul(&rhs)
+    }
+}
+
+impl Sub for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn sub(self, rhs: &NonSmallInt) -> NonSmallInt {
+        match self.safe_sub(rhs) {
+            Some(r) => r,
+            None => panic!("NonSmallInt underflow")
+        }
+    }
+}
+
+impl Add for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn add(self, rhs: &NonSmallInt) -> NonSmallInt {
+        let mut out = Vec::new();
+        let mut carry = 0u32;
+        let max_length = max(self.length(RADIX), rhs.length(RADIX));
+        for (ld, rd) in self.iter_digits(max_length).zip(rhs.iter_digits(max_length)) {
+            let temp: u32 = ld as u32 + rd as u32 + carry;
+            out.push((temp % RADIX as u32) as u8);
+            carry = temp / RADIX as u32;
+        }
+        if carry != 0 {
+            out.push((carry % RADIX as u32) as u8);
+        }
+        NonSmallInt { digits: out }
+    }
+}
+
+impl Add for NonSmallInt {
+    type Output = NonSmallInt;
+    fn add(self, rhs: NonSmallInt) -> NonSmallInt {
+        (&self).add(&rhs)
+    }
+}
+
+impl PartialOrd for NonSmallInt {
+    fn partial_cmp(&self, other: &NonSmallInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NonSmallInt {
+    fn cmp(&self, other: &NonSmallInt) -> Ordering {
+        if self.lt(other) {
+            Ordering::Less
+        } else if self == other {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+impl fmt::Display for NonSmallInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            write!(f, "0")
+        } else {
+            let mut result = write!(f, "");
+            for d in self.digits.iter().rev() {
+                result = write!(f, "{}", d);
+            }
+            result
+        }
+    }
+}
+
+impl Sum for NonSmallInt {
+    fn sum<I>(iter: I) -> NonSmallInt where I: Iterator<Item = NonSmallInt> {
+        let mut acc = NonSmallInt::of(0);
+        for x in iter {
+            acc = acc + x;
+        }
+        acc
+    }
+}
+
+/// A NonSmallInt always displayed with exactly `width` radix 10 digits, zero-padded on the left
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct FixedWidth { value: NonSmallInt, width: usize }
+
+impl FixedWidth {
+
+    /// Wraps value, or None if it has more than width significant digits
+    pub fn new(value: NonSmallInt, width: usize) -> Option<FixedWidth> {
+        if value.length(RADIX) > width {
+            None
+        } else {
+            Some(FixedWidth { value, width })
+        }
+    }
+
+    /// The wrapped number
+    pub fn value(&self) -> &NonSmallInt {
+        &self.value
+    }
+
+    /// Number of digits always displayed
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl fmt::Display for FixedWidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.value.significant_digits();
+        for _ in digits.len()..self.width {
+            write!(f, "0")?;
+        }
+        for d in digits.iter().rev() {
+            write!(f, "{}", d)?;
+        }
+        Ok(())
+    }
+}
+
+/// Why a checked division failed
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DivError {
+    DivisionByZero
+}
+
+impl fmt::Display for DivError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DivError::DivisionByZero => write!(f, "Division by zero is not allowed")
+        }
+    }
+}
+
+impl error::Error for DivError {}
+
+impl BitAnd for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn bitand(self, rhs: &NonSmallInt) -> NonSmallInt {
+        self.bitwise(rhs, |l, r| l & r)
+    }
+}
+
+impl BitOr for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn bitor(self, rhs: &NonSmallInt) -> NonSmallInt {
+        self.bitwise(rhs, |l, r| l | r)
+    }
+}
+
+impl BitXor for &NonSmallInt {
+    type Output = NonSmallInt;
+    fn bitxor(self, rhs: &NonSmallInt) -> NonSmallInt {
+        self.bitwise(rhs, |l, r| l ^ r)
+    }
+}
+
+impl FromStr for NonSmallInt {
+    type Err = ParseNonSmallIntError;
+
+    fn from_str(s: &str) -> Result<NonSmallInt, ParseNonSmallIntError> {
+        let trimmed = s.trim_start();
+        let offset = s.len() - trimmed.len();
+        let trimmed = trimmed.trim_end();
+        if trimmed.is_empty() {
+            return Err(ParseNonSmallIntError::Empty);
+        }
+        let mut digits = Vec::with_capacity(trimmed.len());
+        for (ix, c) in trimmed.char_indices() {
+            match c.to_digit(RADIX as u32) {
+                Some(d) => digits.push(d as u8),
+                None => return Err(ParseNonSmallIntError::InvalidDigit(offset + ix))
+            }
+        }
+        digits.reverse();
+        Ok(NonSmallInt::from_le_digits(digits))
+    }
+}
+
+/// Why a string couldn't be parsed as a NonSmallInt
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ParseNonSmallIntError {
+    /// The input had nothing but whitespace
+    Empty,
+    /// The input had a character other than a decimal digit at this byte position
+    InvalidDigit(usize)
+}
+
+impl fmt::Display for ParseNonSmallIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseNonSmallIntError::Empty => write!(f, "Cannot parse a number from an empty string"),
+            ParseNonSmallIntError::InvalidDigit(ix) => write!(f, "Invalid digit at position {}", ix)
+        }
+    }
+}
+
+impl error::Error for ParseNonSmallIntError {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    impl Arbitrary for NonSmallInt {
+        fn arbitrary<G: Gen>(g: &mut G) -> NonSmallInt {
+            NonSmallInt::of(u64::arbitrary(g))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    /// A NonSmallInt along with the same value as u64
+    struct MinimalNonSmallInt { nsi: NonSmallInt, n: u64 }
+
+    impl MinimalNonSmallInt {
+        fn of(n: u64) -> MinimalNonSmallInt {
+            MinimalNonSmallInt { nsi: NonSmallInt::of(n), n }
+        }
+    }
+
+    impl Arbitrary for MinimalNonSmallInt {
+        fn arbitrary<G: Gen>(g: &mut G) -> MinimalNonSmallInt {
+            let n = u64::arbitrary(g);
+            MinimalNonSmallInt::of(n)
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct SmallInt { n: u8 }
+
+    impl Arbitrary for SmallInt {
+        fn arbitrary<G: Gen>(g: &mut G) -> SmallInt {
+            let n = u8::arbitrary(g) % 10;
+            SmallInt { n }
+        }
+    }
+
+    quickcheck! {
+
+        fn counts_length_correctly(x: MinimalNonSmallInt) -> bool {
+            if x.n > 0 {
+                x.nsi.length(RADIX) == format!("{}", x.n).len()
+            } else {
+                x.nsi.length(RADIX) == 0
+            }
+        }
+
+        fn comparison(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            x.n.cmp(&y.n) == x.nsi.cmp(&y.nsi)
+        }
+
+        fn multiplies_by_u32(x: u32, y: u32) -> bool {
+            let xnsi = NonSmallInt::of(x as u64);
+            let expected = NonSmallInt::of((x * y) as u64);
+
+            &xnsi * y == expected
+        }
+
+        fn div_by_u32(x: MinimalNonSmallInt, y: u32) -> bool {
+            if y != 0 {
+                x.nsi.div_u32(y) == Some((NonSmallInt::of(x.n / y as u64), NonSmallInt::of(x.n % y as u64)))
+            } else {
+                x.nsi.div_u32(y).is_none()
+            }
+        }
+
+        fn subtracts(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            if x.n >= y.n {
+                x.nsi.safe_sub(&y.nsi).unwrap() == NonSmallInt::of(x.n - y.n)
+            } else {
+                x.nsi.safe_sub(&y.nsi).is_none()
+            }
+        }
+
+        fn full_division(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            let result = x.nsi.div_nsi(&y.nsi);
+            match (x.n.checked_div(y.n), x.n.checked_rem(y.n)) {
+                (Some(q), Some(r)) => result == Some((NonSmallInt::of(q), NonSmallInt::of(r))),
+                _ => result.is_none()
+            }
+        }
+
+        fn displays(x: MinimalNonSmallInt) -> bool {
+            format!("{}", x.nsi) == format!("{}", x.n)
+        }
+
+        fn div_operator(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            match x.n.checked_div(y.n) {
+                Some(q) => NonSmallInt::of(q) == (&x.nsi / &y.nsi),
+                None => true
+            }
+        }
+
+        fn rem_operator(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            match x.n.checked_rem(y.n) {
+                Some(r) => NonSmallInt::of(r) == (&x.nsi % &y.nsi),
+                None => true
+            }
+        }
+
+        fn add_operator(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            let lhs = NonSmallInt::of(x.n + y.n);
+            let rhs = &x.nsi + &y.nsi;
+            lhs == rhs
+        }
+
+        fn mul_operator(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            let lhs = NonSmallInt::of(x.n * y.n);
+            let rhs = x.nsi * y.nsi;
+            lhs == rhs
+        }
+
+        fn power(x: MinimalNonSmallInt, y: SmallInt) -> bool {
+            x.nsi.pow(y.n as u32) == NonSmallInt::of(x.n.pow(y.n as u32))
+        }
+
+        fn sum(xs: Vec<MinimalNonSmallInt>) -> bool {
+            let smallsies: Vec<u64> = xs.iter().map(|n| n.n).collect();
+            let bigsies: Vec<NonSmallInt> = xs.into_iter().map(|n| n.nsi).collect();
+
+            NonSmallInt::of(smallsies.iter().sum()) == bigsies.into_iter().sum()
+        }
+
+        fn fits_in_primitives(x: MinimalNonSmallInt) -> bool {
+            x.nsi.fits_u8() == (x.n <= u8::MAX as u64) &&
+                x.nsi.fits_u16() == (x.n <= u16::MAX as u64) &&
+                x.nsi.fits_u32() == (x.n <= u32::MAX as u64) &&
+                x.nsi.fits_u64() && x.nsi.fits_u128()
+        }
+
+        fn from_ascii_matches_parse(xs: Vec<SmallInt>) -> bool {
+            let s: String = xs.iter().map(|d| d.n.to_string()).collect();
+            NonSmallInt::from_ascii(s.as_bytes()) == NonSmallInt::parse(&s)
+        }
+
+        fn from_ascii_rejects_inserted_non_digit(xs: Vec<SmallInt>, position: usize, b: u8) -> bool {
+            let mut bytes: Vec<u8> = xs.iter().map(|d| b'0' + d.n).collect();
+            let b = if b.is_ascii_digit() || (b as char).is_whitespace() { b'x' } else { b };
+            bytes.insert(position % (bytes.len() + 1), b);
+            let parsed = match String::from_utf8(bytes.clone()) {
+                Ok(s) => NonSmallInt::parse(&s),
+                Err(_) => None
+            };
+            NonSmallInt::from_ascii(&bytes).is_none() && parsed.is_none()
+        }
+
+        fn next_with_same_digit_sum(x: u16) -> bool {
+            let digit_sum = |n: u64| format!("{}", n).bytes().map(|b| (b - b'0') as u64).sum::<u64>();
+            let x = x as u64 + 1;
+            let expected = (x+1..).find(|&m| digit_sum(m) == digit_sum(x)).unwrap();
+            NonSmallInt::of(x).next_with_same_digit_sum() == NonSmallInt::of(expected)
+        }
+
+        fn writes_decimal(x: MinimalNonSmallInt) -> bool {
+            let mut out = Vec::new();
+            x.nsi.write_decimal(&mut out).unwrap();
+            out == x.nsi.to_string().into_bytes()
+        }
+
+        fn mul_assign_u32(x: MinimalNonSmallInt, y: u32) -> bool {
+            let mut lhs = x.nsi.clone();
+            lhs *= y;
+            lhs == &x.nsi * y
+        }
+
+        fn product_digit_bound(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            (&x.nsi * &y.nsi).length(RADIX) <= x.nsi.product_digit_bound(&y.nsi)
+        }
+
+        fn mod_pow10(x: MinimalNonSmallInt, n: SmallInt) -> bool {
+            x.nsi.mod_pow10(n.n as usize) == NonSmallInt::of(x.n % 10u64.pow(n.n as u32))
+        }
+
+        fn is_automorphic(x: u32) -> bool {
+            let x = x as u64;
+            let modulus = 10u64.pow(format!("{}", x).len() as u32);
+            NonSmallInt::of(x).is_automorphic() == (x == 0 || (x * x) % modulus == x)
+        }
+
+        fn digit_sum(x: MinimalNonSmallInt) -> bool {
+            x.nsi.digit_sum() == format!("{}", x.n).bytes().map(|b| (b - b'0') as u64).sum::<u64>()
+        }
+
+        fn reversed_twice(x: MinimalNonSmallInt) -> bool {
+            let s: String = format!("{}", x.n).chars().rev().collect();
+            x.nsi.reversed() == NonSmallInt::parse(&s).unwrap() &&
+                (s.starts_with('0') || x.nsi.reversed().reversed() == x.nsi)
+        }
+
+        fn pow_mod(x: MinimalNonSmallInt, e: u16, m: u32) -> bool {
+            let m = m as u64 + 1;
+            let expected = (0..e).fold(1 % m, |acc, _| acc * (x.n % m) % m);
+            x.nsi.pow_mod(&NonSmallInt::of(e as u64), &NonSmallInt::of(m)) == NonSmallInt::of(expected)
+        }
+
+        fn is_probable_prime(x: u32) -> bool {
+            let x = x as u64;
+            let is_prime = x >= 2 && (2..).take_while(|p| p * p <= x).all(|p| x % p != 0);
+            NonSmallInt::of(x).is_probable_prime(12) == is_prime
+        }
+
+        fn rem_u32(x: MinimalNonSmallInt, y: u32) -> bool {
+            y == 0 || x.nsi.rem_u32(y) as u64 == x.n % y as u64
+        }
+
+        fn add_in_place(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            let mut lhs = x.nsi.clone();
+            lhs.add_in_place(&y.nsi);
+            lhs == &x.nsi + &y.nsi
+        }
+
+        fn sum_all(xs: Vec<NonSmallInt>) -> bool {
+            NonSmallInt::sum_all(&xs) == xs.iter().cloned().sum()
+        }
+
+        fn factoradic_round_trip(x: MinimalNonSmallInt) -> bool {
+            NonSmallInt::from_factoradic(&x.nsi.to_factoradic()) == x.nsi
+        }
+
+        fn multiplies_by_u64(x: MinimalNonSmallInt, y: u64) -> bool {
+            &x.nsi * y == &x.nsi * &NonSmallInt::of(y)
+        }
+
+        fn is_divisible_by(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            let expected = match x.n.checked_rem(y.n) {
+                Some(r) => r == 0,
+                None => x.n == 0
+            };
+            x.nsi.is_divisible_by(&y.nsi) == expected
+        }
+
+        fn digits_radix(x: MinimalNonSmallInt, radix: u8) -> bool {
+            let radix = max(radix as u64, 2);
+            let mut expected = Vec::new();
+            let mut n = x.n;
+            while n > 0 {
+                expected.push((n % radix) as u8);
+                n /= radix;
+            }
+            x.nsi.digits_radix(radix as u32) == expected
+        }
+
+        fn power_of_two_terms_sum_back(x: MinimalNonSmallInt) -> bool {
+            let terms = x.nsi.to_power_of_two_terms();
+            terms.iter().map(|&e| NonSmallInt::of(2).pow(e)).sum::<NonSmallInt>() == x.nsi &&
+                terms.len() == x.n.count_ones() as usize
+        }
+
+        fn product_balanced(xs: Vec<SmallInt>) -> bool {
+            let values: Vec<NonSmallInt> = xs.iter().map(|x| NonSmallInt::of(x.n as u64 + 1)).collect();
+            NonSmallInt::product_balanced(&values) == values.iter().fold(NonSmallInt::of(1), |acc, v| &acc * v)
+        }
+
+        fn from_digits_radix_round_trip(x: MinimalNonSmallInt, radix: u8) -> bool {
+            let radix = max(radix as u32, 2);
+            let mut digits = x.nsi.digits_radix(radix);
+            digits.reverse();
+            NonSmallInt::from_digits_radix(&digits, radix) == Some(x.nsi)
+        }
+
+        fn gcd(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            let (mut a, mut b) = (x.n, y.n);
+            while b != 0 {
+                let r = a % b;
+                a = b;
+                b = r;
+            }
+            x.nsi.gcd(&y.nsi) == NonSmallInt::of(a)
+        }
+
+        fn saturating_conversions(x: MinimalNonSmallInt) -> bool {
+            x.nsi.to_u8_saturating() as u64 == min(x.n, u8::MAX as u64) &&
+                x.nsi.to_u16_saturating() as u64 == min(x.n, u16::MAX as u64) &&
+                x.nsi.to_u32_saturating() as u64 == min(x.n, u32::MAX as u64) &&
+                x.nsi.to_u64_saturating() == x.n &&
+                x.nsi.to_u128_saturating() == x.n as u128
+        }
+
+        fn mod_inverse(x: MinimalNonSmallInt, m: u32) -> bool {
+            let m = NonSmallInt::of(m as u64 + 1);
+            match x.nsi.mod_inverse(&m) {
+                Some(inverse) => inverse < m && &(&x.nsi * &inverse) % &m == &NonSmallInt::of(1) % &m,
+                None => x.nsi.gcd(&m) != NonSmallInt::of(1)
+            }
+        }
+
+        fn digit_chars(x: MinimalNonSmallInt) -> bool {
+            x.nsi.to_digit_chars() == x.nsi.to_string().chars().collect::<Vec<char>>()
+        }
+
+        fn isqrt(x: MinimalNonSmallInt) -> bool {
+            let r = x.nsi.isqrt();
+            let next = &r + &NonSmallInt::of(1);
+            r.square() <= x.nsi && next.square() > x.nsi
+        }
+
+        fn mul_mod(x: MinimalNonSmallInt, y: MinimalNonSmallInt, m: u64) -> bool {
+            let m = max(m, 1);
+            let expected = (x.n as u128 * y.n as u128 % m as u128) as u64;
+            x.nsi.mul_mod(&y.nsi, &NonSmallInt::of(m)) == NonSmallInt::of(expected)
+        }
+
+        fn triangular(x: u32) -> bool {
+            let n = x as u64;
+            NonSmallInt::triangular(&NonSmallInt::of(n)) == NonSmallInt::of(n * (n + 1) / 2)
+        }
+
+        fn div_exact(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            let expected = match x.n.checked_rem(y.n) {
+                Some(0) => Some(NonSmallInt::of(x.n / y.n)),
+                _ => None
+            };
+            x.nsi.div_exact(&y.nsi) == expected
+        }
+
+        fn sum_of_squares(x: u16) -> bool {
+            let n = x as u64;
+            NonSmallInt::sum_of_squares(&NonSmallInt::of(n)) == NonSmallInt::of((1..n+1).map(|i| i * i).sum())
+        }
+
+        fn powers(x: MinimalNonSmallInt, n: SmallInt) -> bool {
+            x.nsi.powers().nth(n.n as usize) == Some(x.nsi.pow(n.n as u32))
+        }
+
+        fn is_perfect_square(x: u32) -> bool {
+            let x = x as u64;
+            NonSmallInt::of(x * x).is_perfect_square() && (x < 2 || !NonSmallInt::of(x * x + 1).is_perfect_square())
+        }
+
+        fn digital_root_radix(x: MinimalNonSmallInt, radix: u8) -> bool {
+            // Casting out (radix - 1)s
+            let radix = max(radix as u64, 2);
+            let expected = if x.n == 0 { 0 } else { 1 + (x.n - 1) % (radix - 1) };
+            x.nsi.digital_root_radix(radix as u32) as u64 == expected
+        }
+
+        fn jacobi_symbol_of_primes_is_euler_criterion(x: MinimalNonSmallInt, p: SmallInt) -> bool {
+            let p = [3u64, 5, 7, 11, 13, 17, 19, 23, 29, 31][p.n as usize];
+            let euler = NonSmallInt::of(x.n % p).pow_mod(&NonSmallInt::of((p - 1) / 2), &NonSmallInt::of(p));
+            let expected = if x.n % p == 0 { 0 } else if euler == NonSmallInt::of(1) { 1 } else { -1 };
+            x.nsi.jacobi_symbol(&NonSmallInt::of(p)) == expected
+        }
+
+        fn nth_root(x: MinimalNonSmallInt, n: SmallInt) -> bool {
+            let n = n.n as u32 + 1;
+            let r = x.nsi.nth_root(n);
+            r.pow(n) <= x.nsi && r.succ().pow(n) > x.nsi
+        }
+
+        fn bit_length(x: MinimalNonSmallInt) -> bool {
+            x.nsi.bit_length() == 64 - x.n.leading_zeros() as u64
+        }
+
+        fn range_sum(a: u16, b: u16) -> bool {
+            let (a, b) = (a as u64, b as u64);
+            let expected = if a <= b { Some(NonSmallInt::of((a..b+1).sum())) } else { None };
+            NonSmallInt::range_sum(&NonSmallInt::of(a), &NonSmallInt::of(b)) == expected
+        }
+
+        fn mod97(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            let nsi = &x.nsi * &y.nsi;
+            nsi.mod97() == nsi.rem_u32(97)
+        }
+
+        fn binary_gcd(x: u64, y: u64) -> bool {
+            fn gcd(a: u64, b: u64) -> u64 { if b == 0 { a } else { gcd(b, a % b) } }
+            let (a, b) = (NonSmallInt::of(x), NonSmallInt::of(y));
+            let g = a.binary_gcd(&b);
+            g == a.gcd(&b) && g == NonSmallInt::of(gcd(x, y))
+        }
+
+        fn binary_hamming_distance(x: u64, y: u64) -> bool {
+            NonSmallInt::of(x).binary_hamming_distance(&NonSmallInt::of(y)) == (x ^ y).count_ones() as u64
+        }
+
+        fn carries_when_adding(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            // Kummer: each carry leaves the digit sum of the total nine short of the digit sums of the terms
+            let carries = x.nsi.carries_when_adding(&y.nsi) as u64;
+            (&x.nsi + &y.nsi).digit_sum() + 9 * carries == x.nsi.digit_sum() + y.nsi.digit_sum()
+        }
+
+        fn icbrt(x: MinimalNonSmallInt) -> bool {
+            let r = x.nsi.icbrt();
+            r == x.nsi.nth_root(3) && r.pow(3) <= x.nsi && r.succ().pow(3) > x.nsi
+        }
+
+        fn polynomial_hash(x: MinimalNonSmallInt) -> bool {
+            // With the base equal to the radix the fingerprint is the remainder
+            x.nsi.polynomial_hash(10, 1000003) == x.nsi.rem_u32(1000003)
+        }
+
+        fn mod11(x: MinimalNonSmallInt, y: MinimalNonSmallInt) -> bool {
+            let nsi = &x.nsi * &y.nsi;
+            nsi.mod11() == nsi.rem_u32(11)
+        }
+
+        fn bitwise_ops(x: u64, y: u64) -> bool {
+            let (a, b) = (NonSmallInt::of(x), NonSmallInt::of(y));
+            &a & &b == NonSmallInt::of(x & y) && &a | &b == NonSmallInt::of(x | y) && &a ^ &b == NonSmallInt::of(x ^ y)
+                && a.bitand(&b) == &a & &b && a.bitor(&b) == &a | &b && a.bitxor(&b) == &a ^ &b
+        }
+
+        fn single_bits(x: u64, i: u8) -> bool {
+            let (nsi, i) = (NonSmallInt::of(x), (i % 64) as u64);
+            nsi.test_bit(i) == (x >> i & 1 == 1)
+                && nsi.set_bit(i) == NonSmallInt::of(x | 1 << i)
+                && nsi.clear_bit(i) == NonSmallInt::of(x & !(1 << i))
+        }
+
+        fn increment_digit_churn(x: u32) -> bool {
+            let (before, after) = (format!("{:0>11}", x), format!("{:0>11}", x as u64 + 1));
+            let changed = before.chars().zip(after.chars()).filter(|&(b, a)| b != a).count();
+            NonSmallInt::of(x as u64).increment_digit_churn() == changed
+        }
+
+        fn mod_sqrt(x: u32) -> bool {
+            let prime = NonSmallInt::of(1000000007);
+            let x = NonSmallInt::of(x as u64);
+            let square = x.mul_mod(&x, &prime);
+            let root = square.mod_sqrt(&prime).unwrap();
+            root.mul_mod(&root, &prime) == square && (root == x || &prime - &root == x)
+        }
+
+        fn negabinary_round_trip(x: MinimalNonSmallInt) -> bool {
+            let digits = x.nsi.to_negabinary();
+            digits.last() != Some(&0) && NonSmallInt::from_negabinary(&digits) == Some(x.nsi)
+        }
+
+        fn continued_fraction_of_ratio(x: u32, y: u32) -> bool {
+            let (num, den) = (NonSmallInt::of(x as u64), NonSmallInt::of(y as u64 + 1));
+            let terms: Vec<u64> = NonSmallInt::continued_fraction_of_ratio(&num, &den).iter()
+                .map(|t| t.to_u64_saturating())
+                .collect();
+            // The last convergent is the ratio in lowest terms
+            let (h, k) = NonSmallInt::continued_fraction_convergent(&terms);
+            let g = num.gcd(&den);
+            h == &num / &g && k == &den / &g
+        }
+
+        fn factor_out_twos(x: u64) -> bool {
+            let x = max(x, 1);
+            let (d, s) = NonSmallInt::of(x).factor_out_twos();
+            s == x.trailing_zeros() && d == NonSmallInt::of(x >> s)
+        }
+
+        fn parses_displayed(x: u64) -> bool {
+            let parsed: Result<NonSmallInt, ParseNonSmallIntError> = format!("{}", x).parse();
+            parsed.map(|n| n.to_u64_saturating()) == Ok(x)
+        }
+
+        fn divisors(x: u16) -> bool {
+            let n = x as u64 + 1;
+            let expected: Vec<NonSmallInt> = (1..n+1).filter(|&d| n % d == 0).map(NonSmallInt::of).collect();
+            NonSmallInt::of(n).divisors(n) == Some(expected)
+        }
+    }
+
+    #[test]
+    fn double_sided_iter_digits() {
+        let nsi = NonSmallInt::of(654321);
+        let mut iter = nsi.iter_digits(10);
+
+        assert_eq!(Some(0), iter.next_back());
+        assert_eq!(Some(0), iter.next_back());
         assert_eq!(Some(0), iter.next_back());
         assert_eq!(Some(0), iter.next_back());
-        assert_eq!(Some(0), iter.next_back());
-        assert_eq!(Some(0), iter.next_back());
         assert_eq!(Some(1), iter.next());
         assert_eq!(Some(6), iter.next_back());
         assert_eq!(Some(5), iter.next_back());
@@ -599,8 +2899,1238 @@ mod tests {
         assert_eq!(None, iter.next());
         assert_eq!(None, iter.next_back());
 
-        let reversed: Vec<u8> = nsi.iter_digits(6).rev().collect();
-        let reversed_expected: Vec<u8> = (1..7).rev().collect();
-        assert_eq!(reversed, reversed_expected)
+        let reversed: Vec<u8> = nsi.iter_digits(6).rev().collect();
+        let reversed_expected: Vec<u8> = (1..7).rev().collect();
+        assert_eq!(reversed, reversed_expected)
+    }
+
+    #[test]
+    fn fits_at_boundaries() {
+        assert_eq!(U8_MAX_DIGITS, u8::MAX.to_string().as_bytes());
+        assert_eq!(U16_MAX_DIGITS, u16::MAX.to_string().as_bytes());
+        assert_eq!(U32_MAX_DIGITS, u32::MAX.to_string().as_bytes());
+        assert_eq!(U64_MAX_DIGITS, u64::MAX.to_string().as_bytes());
+        assert_eq!(U128_MAX_DIGITS, u128::MAX.to_string().as_bytes());
+
+        let one = NonSmallInt::of(1);
+
+        let max_u8 = NonSmallInt::of(u8::MAX as u64);
+        assert!(max_u8.fits_u8());
+        assert!(!(&max_u8 + &one).fits_u8());
+
+        let max_u16 = NonSmallInt::of(u16::MAX as u64);
+        assert!(max_u16.fits_u16());
+        assert!(!(&max_u16 + &one).fits_u16());
+
+        let max_u32 = NonSmallInt::of(u32::MAX as u64);
+        assert!(max_u32.fits_u32());
+        assert!(!(&max_u32 + &one).fits_u32());
+
+        let max_u64 = NonSmallInt::of(u64::MAX);
+        assert!(max_u64.fits_u64());
+        assert!(!(&max_u64 + &one).fits_u64());
+
+        let max_u128 = NonSmallInt::parse("340282366920938463463374607431768211455").unwrap();
+        assert!(max_u128.fits_u128());
+        assert!(!(&max_u128 + &one).fits_u128());
+
+        assert!(NonSmallInt::parse("000255").unwrap().fits_u8());
+        assert!(NonSmallInt::of(0).fits_u8());
+    }
+
+    #[test]
+    fn digit_product() {
+        assert_eq!(NonSmallInt::of(234).digit_product(), NonSmallInt::of(24));
+        assert_eq!(NonSmallInt::of(205).digit_product(), NonSmallInt::of(0));
+        assert_eq!(NonSmallInt::of(0).digit_product(), NonSmallInt::of(0));
+        assert_eq!(NonSmallInt::parse("99999999999999999999").unwrap().digit_product(), NonSmallInt::of(9).pow(20));
+    }
+
+    #[test]
+    fn from_ascii_rejects_non_digits() {
+        assert_eq!(NonSmallInt::from_ascii(b" 00123 "), Some(NonSmallInt::of(123)));
+        assert_eq!(NonSmallInt::from_ascii(b"12a3"), None);
+        assert_eq!(NonSmallInt::from_ascii(b"-5"), None);
+        assert_eq!(NonSmallInt::from_ascii(b"1\xff"), None);
+        assert_eq!(NonSmallInt::from_ascii(b"\t\n 42\r\n"), Some(NonSmallInt::of(42)));
+        assert_eq!(NonSmallInt::from_ascii(b"   "), NonSmallInt::parse("   "));
+    }
+
+    #[test]
+    fn next_with_same_digit_sum_regroups() {
+        assert_eq!(NonSmallInt::of(19).next_with_same_digit_sum(), NonSmallInt::of(28));
+        assert_eq!(NonSmallInt::of(100).next_with_same_digit_sum(), NonSmallInt::of(1000));
+        assert_eq!(NonSmallInt::of(109).next_with_same_digit_sum(), NonSmallInt::of(118));
+        assert_eq!(NonSmallInt::of(999).next_with_same_digit_sum(), NonSmallInt::of(1899));
+    }
+
+    #[test]
+    fn writes_decimal_across_chunks() {
+        let long = "1234567890".repeat(500);
+        let exactly_one_chunk = format!("9{}", "0".repeat(4095));
+        let one_past_chunk = format!("9{}1", "0".repeat(4095));
+        for s in &[long, exactly_one_chunk, one_past_chunk] {
+            let nsi = NonSmallInt::parse(s).unwrap();
+            let mut out = Vec::new();
+            nsi.write_decimal(&mut out).unwrap();
+            assert_eq!(&out, s.as_bytes());
+        }
+    }
+
+    #[test]
+    fn factorials() {
+        assert_eq!(NonSmallInt::factorial(0), NonSmallInt::of(1));
+        assert_eq!(NonSmallInt::factorial(20), NonSmallInt::of(2432902008176640000));
+        assert_eq!(NonSmallInt::factorial_streaming(0), NonSmallInt::of(1));
+        assert_eq!(NonSmallInt::factorial_streaming(20), NonSmallInt::of(2432902008176640000));
+        assert_eq!(NonSmallInt::factorial_streaming(100), NonSmallInt::factorial(100));
+    }
+
+    #[test]
+    fn product_digit_bound_pairs() {
+        let pairs = [(9u64, 9u64), (99, 99), (10, 10), (999, 1), (12345, 6789)];
+        for &(x, y) in pairs.iter() {
+            let (x, y) = (NonSmallInt::of(x), NonSmallInt::of(y));
+            assert!((&x * &y).length(RADIX) <= x.product_digit_bound(&y));
+        }
+        assert_eq!(NonSmallInt::of(99).product_digit_bound(&NonSmallInt::of(99)), 4);
+        assert_eq!((&NonSmallInt::of(99) * &NonSmallInt::of(99)).length(RADIX), 4);
+        assert_eq!(NonSmallInt::of(0).product_digit_bound(&NonSmallInt::of(123)), 3);
+    }
+
+    #[test]
+    fn automorphic_numbers() {
+        assert!(NonSmallInt::of(76).is_automorphic());
+        assert!(!NonSmallInt::of(77).is_automorphic());
+        let automorphic: Vec<u64> = (0..1000).filter(|&n| NonSmallInt::of(n).is_automorphic()).collect();
+        assert_eq!(automorphic, vec![0, 1, 5, 6, 25, 76, 376, 625]);
+    }
+
+    #[test]
+    fn digit_sum_until_target_length() {
+        let big = NonSmallInt::parse(&"987654321".repeat(40)).unwrap();
+        // 40 * 45 = 1800 -> 9
+        assert_eq!(big.digit_sum_until(1), NonSmallInt::of(9));
+        assert_eq!(big.digit_sum_until(2), NonSmallInt::of(9));
+        assert_eq!(big.digit_sum_until(4), NonSmallInt::of(1800));
+        assert_eq!(NonSmallInt::of(99999999).digit_sum_until(2), NonSmallInt::of(72));
+        assert_eq!(NonSmallInt::of(99999999).digit_sum_until(1), NonSmallInt::of(9));
+        assert_eq!(NonSmallInt::of(42).digit_sum_until(5), NonSmallInt::of(42));
+    }
+
+    #[test]
+    fn reverse_of() {
+        assert!(NonSmallInt::of(123).is_reverse_of(&NonSmallInt::of(321)));
+        assert!(NonSmallInt::of(321).is_reverse_of(&NonSmallInt::of(123)));
+        assert!(NonSmallInt::of(121).is_reverse_of(&NonSmallInt::of(121)));
+        assert!(!NonSmallInt::of(123).is_reverse_of(&NonSmallInt::of(123)));
+
+        // Trailing zeros disappear on reversal, which isn't treated as a reversal relationship
+        assert_eq!(NonSmallInt::of(100).reversed(), NonSmallInt::of(1));
+        assert!(!NonSmallInt::of(1).is_reverse_of(&NonSmallInt::of(100)));
+        assert!(!NonSmallInt::of(100).is_reverse_of(&NonSmallInt::of(1)));
+        assert!(!NonSmallInt::of(21).is_reverse_of(&NonSmallInt::of(120)));
+    }
+
+    #[test]
+    fn probable_primes() {
+        let primes: Vec<u64> = (0..50).filter(|&n| NonSmallInt::of(n).is_probable_prime(12)).collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]);
+        // Carmichael numbers and a strong pseudoprime to base 2
+        assert!(!NonSmallInt::of(561).is_probable_prime(12));
+        assert!(!NonSmallInt::of(41041).is_probable_prime(12));
+        assert!(!NonSmallInt::of(2047).is_probable_prime(12));
+        assert!(NonSmallInt::of(2305843009213693951).is_probable_prime(12));
+        // Zero rounds still tries base 2, which 41 * 43 fails despite passing trial division
+        assert!(!NonSmallInt::of(1763).is_probable_prime(0));
+        assert!(NonSmallInt::of(1759).is_probable_prime(0));
+    }
+
+    #[test]
+    fn emirps() {
+        assert!(NonSmallInt::of(13).is_emirp());
+        assert!(NonSmallInt::of(107).is_emirp());
+        assert!(!NonSmallInt::of(11).is_emirp());
+        assert!(!NonSmallInt::of(19).is_emirp());
+        assert!(!NonSmallInt::of(14).is_emirp());
+    }
+
+    #[test]
+    fn small_residues() {
+        let nsi = NonSmallInt::parse("123456789012345678901234567890").unwrap();
+        let primes = [2, 3, 5, 7, 11, 13, 4294967291];
+        let expected: Vec<u32> = primes.iter().map(|&p| nsi.rem_u32(p)).collect();
+        assert_eq!(nsi.small_residues(&primes), expected);
+        for (&p, &r) in primes.iter().zip(expected.iter()) {
+            assert_eq!(NonSmallInt::of(r as u64), &nsi % p);
+        }
+        assert_eq!(NonSmallInt::of(100).small_residues(&[3, 7, 11]), vec![1, 2, 1]);
+        assert!(NonSmallInt::of(100).small_residues(&[]).is_empty());
+    }
+
+    #[test]
+    fn sum_all_carries() {
+        let values = vec![NonSmallInt::of(999), NonSmallInt::of(1), NonSmallInt::parse("99999999999999999999").unwrap()];
+        assert_eq!(NonSmallInt::sum_all(&values), NonSmallInt::parse("100000000000000000999").unwrap());
+        assert_eq!(NonSmallInt::sum_all(&[]), NonSmallInt::of(0));
+    }
+
+    #[test]
+    fn kaprekar_steps() {
+        let mut n = NonSmallInt::of(3524);
+        let mut steps = Vec::new();
+        while n != NonSmallInt::of(6174) {
+            n = n.kaprekar_step(4);
+            steps.push(n.clone());
+        }
+        assert_eq!(steps, vec![NonSmallInt::of(3087), NonSmallInt::of(8352), NonSmallInt::of(6174)]);
+        assert_eq!(NonSmallInt::of(6174).kaprekar_step(4), NonSmallInt::of(6174));
+        // Padding matters: 0999 -> 9990 - 0999
+        assert_eq!(NonSmallInt::of(999).kaprekar_step(4), NonSmallInt::of(8991));
+        assert_eq!(NonSmallInt::of(495).kaprekar_step(3), NonSmallInt::of(495));
+        assert_eq!(NonSmallInt::of(1111).kaprekar_step(4), NonSmallInt::of(0));
+    }
+
+    #[test]
+    fn estimate_product_cost() {
+        assert_eq!(NonSmallInt::of(123).estimate_product_cost(&NonSmallInt::of(45)), 6);
+        assert_eq!(NonSmallInt::of(9).estimate_product_cost(&NonSmallInt::of(9)), 1);
+        let big = NonSmallInt::parse(&"7".repeat(1000)).unwrap();
+        assert_eq!(big.estimate_product_cost(&big), 1000000);
+        assert_eq!(NonSmallInt::of(0).estimate_product_cost(&big), 0);
+    }
+
+    #[test]
+    fn from_factoradic() {
+        assert_eq!(NonSmallInt::from_factoradic(&[0, 1, 1, 0]), NonSmallInt::of(3));
+        assert_eq!(NonSmallInt::from_factoradic(&[0, 0, 2, 3]), NonSmallInt::of(22));
+        assert_eq!(NonSmallInt::from_factoradic(&[0, 1, 2, 3, 4]), NonSmallInt::of(119));
+        assert_eq!(NonSmallInt::from_factoradic(&[]), NonSmallInt::of(0));
+        let max_of_20: Vec<u32> = (0..21).collect();
+        assert_eq!(NonSmallInt::from_factoradic(&max_of_20), &NonSmallInt::factorial(21) - &NonSmallInt::of(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_factoradic_rejects_out_of_bounds_digit() {
+        NonSmallInt::from_factoradic(&[0, 2]);
+    }
+
+    #[test]
+    fn to_factoradic() {
+        assert_eq!(NonSmallInt::of(0).to_factoradic(), vec![0]);
+        assert_eq!(NonSmallInt::of(3).to_factoradic(), vec![0, 1, 1]);
+        assert_eq!(NonSmallInt::of(22).to_factoradic(), vec![0, 0, 2, 3]);
+        assert_eq!(NonSmallInt::of(119).to_factoradic(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(NonSmallInt::factorial(25).to_factoradic().len(), 26);
+    }
+
+    #[test]
+    fn sigma_from_factors() {
+        let of = NonSmallInt::of;
+        assert_eq!(NonSmallInt::sigma_from_factors(&[]), of(1));
+        assert_eq!(NonSmallInt::sigma_from_factors(&[(of(2), 2), (of(3), 1)]), of(28));
+        assert_eq!(NonSmallInt::sigma_from_factors(&[(of(7), 1)]), of(8));
+        // 2^3 * 3^2 * 5 = 360
+        assert_eq!(NonSmallInt::sigma_from_factors(&[(of(2), 3), (of(3), 2), (of(5), 1)]), of(1170));
+    }
+
+    #[test]
+    fn perfect_from_factors() {
+        let of = NonSmallInt::of;
+        assert!(NonSmallInt::is_perfect_from_factors(&[(of(2), 2), (of(7), 1)], &of(28)));
+        assert!(!NonSmallInt::is_perfect_from_factors(&[(of(2), 2), (of(3), 1)], &of(12)));
+        // 2^60 * (2^61 - 1) from the Mersenne prime 2^61 - 1
+        let mersenne = of(2305843009213693951);
+        let perfect = &of(2).pow(60) * &mersenne;
+        assert!(NonSmallInt::is_perfect_from_factors(&[(of(2), 60), (mersenne, 1)], &perfect));
+    }
+
+    #[test]
+    fn continued_fraction_convergents() {
+        let of = NonSmallInt::of;
+        // Golden ratio convergents are ratios of consecutive Fibonacci numbers
+        assert_eq!(NonSmallInt::continued_fraction_convergent(&[1; 10]), (of(89), of(55)));
+        assert_eq!(NonSmallInt::continued_fraction_convergent(&[1; 1]), (of(1), of(1)));
+        // pi ~ [3; 7, 15, 1] = 355/113
+        assert_eq!(NonSmallInt::continued_fraction_convergent(&[3, 7, 15, 1]), (of(355), of(113)));
+        assert_eq!(NonSmallInt::continued_fraction_convergent(&[]), (of(1), of(0)));
+        let (h, k) = NonSmallInt::continued_fraction_convergent(&[u64::MAX, u64::MAX]);
+        assert_eq!(h, &(&of(u64::MAX) * &of(u64::MAX)) + &of(1));
+        assert_eq!(k, of(u64::MAX));
+    }
+
+    #[test]
+    fn in_progression() {
+        let of = NonSmallInt::of;
+        assert!(of(17).is_in_progression(&of(2), &of(5)));
+        assert!(of(2).is_in_progression(&of(2), &of(5)));
+        assert!(!of(18).is_in_progression(&of(2), &of(5)));
+        assert!(!of(1).is_in_progression(&of(2), &of(5)));
+        assert!(of(7).is_in_progression(&of(7), &of(0)));
+        assert!(!of(8).is_in_progression(&of(7), &of(0)));
+        let huge_start = NonSmallInt::parse("100000000000000000000000").unwrap();
+        let huge_index = &huge_start + &(&of(3) * &of(1000000007));
+        assert!(huge_index.is_in_progression(&huge_start, &of(1000000007)));
+    }
+
+    #[test]
+    fn power_of_two_terms() {
+        assert_eq!(NonSmallInt::of(13).to_power_of_two_terms(), vec![0, 2, 3]);
+        assert_eq!(NonSmallInt::of(0).to_power_of_two_terms(), vec![]);
+        assert_eq!(NonSmallInt::of(2).pow(100).to_power_of_two_terms(), vec![100]);
+        assert_eq!(NonSmallInt::of(255).digits_radix(16), vec![15, 15]);
+        assert_eq!(NonSmallInt::of(255).digits_radix(256), vec![255]);
+    }
+
+    #[test]
+    fn product_balanced_many_operands() {
+        let values: Vec<NonSmallInt> = (1..301).map(NonSmallInt::of).collect();
+        assert_eq!(NonSmallInt::product_balanced(&values), NonSmallInt::factorial_streaming(300));
+        assert_eq!(NonSmallInt::product_balanced(&[]), NonSmallInt::of(1));
+    }
+
+    #[test]
+    fn from_digits_radix_validates() {
+        assert_eq!(NonSmallInt::from_digits_radix(&[1, 1, 0, 1], 2), Some(NonSmallInt::of(13)));
+        assert_eq!(NonSmallInt::from_digits_radix(&[15, 15], 16), Some(NonSmallInt::of(255)));
+        assert_eq!(NonSmallInt::from_digits_radix(&[], 10), Some(NonSmallInt::of(0)));
+        assert_eq!(NonSmallInt::from_digits_radix(&[1, 2], 2), None);
+        assert_eq!(NonSmallInt::from_digits_radix(&[0], 1), None);
+    }
+
+    #[test]
+    fn multiplicative_orders() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(2).multiplicative_order(&of(7)), Some(of(3)));
+        assert_eq!(of(3).multiplicative_order(&of(7)), Some(of(6)));
+        assert_eq!(of(10).multiplicative_order(&of(7)), Some(of(6)));
+        assert_eq!(of(9).multiplicative_order(&of(7)), Some(of(3)));
+        assert_eq!(of(5).multiplicative_order(&of(1)), Some(of(1)));
+        assert_eq!(of(2).multiplicative_order(&of(8)), None);
+        assert_eq!(of(2).multiplicative_order(&of(0)), None);
+    }
+
+    #[test]
+    fn armstrong_numbers() {
+        assert!(NonSmallInt::of(153).is_armstrong());
+        assert!(!NonSmallInt::of(154).is_armstrong());
+        let armstrong: Vec<u64> = (1..10000).filter(|&n| NonSmallInt::of(n).is_armstrong()).collect();
+        assert_eq!(armstrong, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 153, 370, 371, 407, 1634, 8208, 9474]);
+    }
+
+    #[test]
+    fn rotates_digits() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(142857).rotate_digits(1), of(714285));
+        assert_eq!(of(142857).rotate_digits(2), of(571428));
+        assert_eq!(of(142857).rotate_digits(6), of(142857));
+        assert_eq!(of(142857).rotate_digits(7), of(714285));
+        assert_eq!(of(120).rotate_digits(1), of(12));
+        assert_eq!(of(120).rotate_digits(2), of(201));
+        assert_eq!(of(0).rotate_digits(3), of(0));
+    }
+
+    #[cfg(feature = "slow-div")]
+    quickcheck! {
+        fn naive_division_matches_long_division(x: u16, y: u8) -> bool {
+            let (x, y) = (NonSmallInt::of(x as u64), NonSmallInt::of(y as u64));
+            x.div_nsi_naive(&y) == x.div_nsi(&y)
+        }
+
+        fn naive_division_matches_with_multi_digit_divisors(x: u32, y: u16) -> bool {
+            let (x, y) = (NonSmallInt::of(x as u64 % 1000000), NonSmallInt::of(y as u64 + 10));
+            x.div_nsi_naive(&y) == x.div_nsi(&y)
+        }
+    }
+
+    #[test]
+    fn divisor_count_from_factors() {
+        let of = NonSmallInt::of;
+        assert_eq!(NonSmallInt::divisor_count_from_factors(&[(of(2), 2), (of(3), 1), (of(5), 1)]), of(12));
+        assert_eq!(NonSmallInt::divisor_count_from_factors(&[]), of(1));
+        assert_eq!(NonSmallInt::divisor_count_from_factors(&[(of(2), u32::MAX), (of(3), u32::MAX)]), of(1 << 32).pow(2));
+    }
+
+    #[test]
+    fn saturating_conversions_at_boundaries() {
+        let one = NonSmallInt::of(1);
+
+        let max_u8 = NonSmallInt::of(u8::MAX as u64);
+        assert_eq!(max_u8.to_u8_saturating(), u8::MAX);
+        assert_eq!((&max_u8 + &one).to_u8_saturating(), u8::MAX);
+        assert_eq!((&max_u8 - &one).to_u8_saturating(), u8::MAX - 1);
+
+        let max_u16 = NonSmallInt::of(u16::MAX as u64);
+        assert_eq!(max_u16.to_u16_saturating(), u16::MAX);
+        assert_eq!((&max_u16 + &one).to_u16_saturating(), u16::MAX);
+        assert_eq!((&max_u16 - &one).to_u16_saturating(), u16::MAX - 1);
+
+        let max_u32 = NonSmallInt::of(u32::MAX as u64);
+        assert_eq!(max_u32.to_u32_saturating(), u32::MAX);
+        assert_eq!((&max_u32 + &one).to_u32_saturating(), u32::MAX);
+        assert_eq!((&max_u32 - &one).to_u32_saturating(), u32::MAX - 1);
+
+        let max_u64 = NonSmallInt::of(u64::MAX);
+        assert_eq!(max_u64.to_u64_saturating(), u64::MAX);
+        assert_eq!((&max_u64 + &one).to_u64_saturating(), u64::MAX);
+        assert_eq!((&max_u64 - &one).to_u64_saturating(), u64::MAX - 1);
+
+        let max_u128 = NonSmallInt::parse("340282366920938463463374607431768211455").unwrap();
+        assert_eq!(max_u128.to_u128_saturating(), u128::MAX);
+        assert_eq!((&max_u128 + &one).to_u128_saturating(), u128::MAX);
+        assert_eq!((&max_u128 - &one).to_u128_saturating(), u128::MAX - 1);
+    }
+
+    #[test]
+    fn champernowne_concat() {
+        assert_eq!(NonSmallInt::champernowne_concat(12), NonSmallInt::of(123456789101112));
+        assert_eq!(NonSmallInt::champernowne_concat(1), NonSmallInt::of(1));
+        assert_eq!(NonSmallInt::champernowne_concat(0), NonSmallInt::of(0));
+        let expected: String = (1..1001).map(|i| i.to_string()).collect();
+        assert_eq!(NonSmallInt::champernowne_concat(1000).to_string(), expected);
+    }
+
+    #[test]
+    fn extreme_digits() {
+        assert_eq!(NonSmallInt::of(195038).max_digit(), (9, 1));
+        assert_eq!(NonSmallInt::of(195038).min_digit(), (0, 3));
+        assert_eq!(NonSmallInt::of(9191).max_digit(), (9, 0));
+        assert_eq!(NonSmallInt::of(9191).min_digit(), (1, 1));
+        assert_eq!(NonSmallInt::parse("000725").unwrap().min_digit(), (2, 1));
+        assert_eq!(NonSmallInt::of(0).max_digit(), (0, 0));
+    }
+
+    #[test]
+    fn checked_add_bounded() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(500).checked_add_bounded(&of(499), 3), Some(of(999)));
+        assert_eq!(of(500).checked_add_bounded(&of(500), 3), None);
+        assert_eq!(of(1000).checked_add_bounded(&of(0), 3), None);
+        assert_eq!(of(0).checked_add_bounded(&of(0), 0), Some(of(0)));
+    }
+
+    #[test]
+    fn chinese_remainder_theorem() {
+        let of = NonSmallInt::of;
+        let moduli = [of(3), of(5), of(7)];
+        let residues = of(52).residues_mod(&moduli);
+        assert_eq!(residues, vec![of(1), of(2), of(3)]);
+        assert_eq!(NonSmallInt::crt(&residues, &moduli), Some(of(52)));
+
+        let big_moduli = [of(1000000007), of(998244353), of(4294967291)];
+        let value = of(123456789123456789);
+        assert_eq!(NonSmallInt::crt(&value.residues_mod(&big_moduli), &big_moduli), Some(value));
+
+        assert_eq!(NonSmallInt::crt(&[of(1), of(1)], &[of(4), of(6)]), None);
+        assert_eq!(NonSmallInt::crt(&[of(1)], &[of(4), of(5)]), None);
+        assert_eq!(NonSmallInt::crt(&[], &[]), Some(of(0)));
+        assert_eq!(NonSmallInt::crt(&[of(1), of(2)], &[of(0), of(5)]), None);
+        assert_eq!(NonSmallInt::crt(&[of(0), of(2)], &[of(1), of(5)]), Some(of(2)));
+        assert_eq!(of(3).mod_inverse(&of(7)), Some(of(5)));
+    }
+
+    #[test]
+    fn to_digit_chars() {
+        assert_eq!(NonSmallInt::of(0).to_digit_chars(), vec!['0']);
+        assert_eq!(NonSmallInt::parse("0042").unwrap().to_digit_chars(), vec!['4', '2']);
+    }
+
+    #[test]
+    fn weighted_digit_sum() {
+        // 5*1 + 4*3 + 3*1 + 2*3 + 1*1
+        assert_eq!(NonSmallInt::of(12345).weighted_digit_sum(&[1, 3]), 27);
+        assert_eq!(NonSmallInt::of(12345).weighted_digit_sum(&[1]), 15);
+        assert_eq!(NonSmallInt::of(12345).weighted_digit_sum(&[]), 0);
+        assert_eq!(NonSmallInt::of(99).weighted_digit_sum(&[u32::MAX]), 18 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn ean13_validation() {
+        assert!(NonSmallInt::of(4006381333931).is_ean13_valid());
+        assert!(NonSmallInt::of(9780306406157).is_ean13_valid());
+        assert!(!NonSmallInt::of(4006381333932).is_ean13_valid());
+        assert!(!NonSmallInt::of(4006381339331).is_ean13_valid());
+        assert!(!NonSmallInt::of(0).is_ean13_valid());
+        assert!(!NonSmallInt::of(40063813339310).is_ean13_valid());
+        // The UPC-A code 036000291452 promoted to EAN-13 as 0036000291452
+        assert!(NonSmallInt::parse("0036000291452").unwrap().is_ean13_valid());
+        assert!(!NonSmallInt::parse("0036000291453").unwrap().is_ean13_valid());
+    }
+
+    #[test]
+    fn geometric_mean_floor() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(4).geometric_mean_floor(&of(9)), of(6));
+        assert_eq!(of(2).geometric_mean_floor(&of(3)), of(2));
+        assert_eq!(of(0).geometric_mean_floor(&of(9)), of(0));
+        assert_eq!(of(u64::MAX).geometric_mean_floor(&of(u64::MAX)), of(u64::MAX));
+        assert_eq!(of(99).isqrt(), of(9));
+        assert_eq!(of(100).isqrt(), of(10));
+    }
+
+    #[test]
+    fn falling_factorial() {
+        let of = NonSmallInt::of;
+        assert_eq!(NonSmallInt::falling_factorial(&of(10), 3), of(720));
+        assert_eq!(NonSmallInt::falling_factorial(&of(10), 0), of(1));
+        assert_eq!(NonSmallInt::falling_factorial(&of(10), 10), NonSmallInt::factorial(10));
+        assert_eq!(NonSmallInt::falling_factorial(&of(10), 11), of(0));
+        assert_eq!(NonSmallInt::falling_factorial(&of(0), 0), of(1));
+        let big = NonSmallInt::parse("100000000000000000000").unwrap();
+        assert_eq!(NonSmallInt::falling_factorial(&big, 2), &big * &(&big - &of(1)));
+    }
+
+    #[test]
+    fn palindromic_primes() {
+        assert!(NonSmallInt::of(131).is_palindromic_prime(12));
+        assert!(!NonSmallInt::of(133).is_palindromic_prime(12));
+        assert!(!NonSmallInt::of(121).is_palindromic_prime(12));
+        assert!(!NonSmallInt::of(13).is_palindromic_prime(12));
+        assert!(NonSmallInt::of(0).is_palindrome());
+        assert!(!NonSmallInt::of(10).is_palindrome());
+    }
+
+    #[test]
+    fn lychrel_resolution() {
+        assert_eq!(NonSmallInt::of(56).lychrel_resolves(10), Some(1));
+        assert_eq!(NonSmallInt::of(57).lychrel_resolves(10), Some(2));
+        assert_eq!(NonSmallInt::of(89).lychrel_resolves(30), Some(24));
+        assert_eq!(NonSmallInt::of(89).lychrel_resolves(23), None);
+        assert_eq!(NonSmallInt::of(11).lychrel_resolves(10), Some(1));
+        assert_eq!(NonSmallInt::of(196).lychrel_resolves(50), None);
+        assert_eq!(NonSmallInt::of(56).lychrel_resolves(0), None);
+    }
+
+    #[test]
+    fn mul_mod_small_cases() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(7).mul_mod(&of(8), &of(10)), of(6));
+        assert_eq!(of(7).mul_mod(&of(8), &of(1)), of(0));
+    }
+
+    #[test]
+    fn grouped_digits() {
+        assert_eq!(NonSmallInt::of(12345678).grouped_digits(&[3, 3]), vec!["123", "456", "78"]);
+        assert_eq!(NonSmallInt::of(123456).grouped_digits(&[3, 3]), vec!["123", "456"]);
+        assert_eq!(NonSmallInt::of(12345).grouped_digits(&[3, 3, 3]), vec!["123", "45"]);
+        assert_eq!(NonSmallInt::of(12345).grouped_digits(&[]), vec!["12345"]);
+        assert_eq!(NonSmallInt::of(0).grouped_digits(&[2]), vec!["0"]);
+    }
+
+    #[test]
+    fn triangular_numbers() {
+        let of = NonSmallInt::of;
+        assert_eq!(NonSmallInt::triangular(&of(100)), of(5050));
+        assert_eq!(NonSmallInt::triangular(&of(0)), of(0));
+        let summed: NonSmallInt = (1..1001).map(of).sum();
+        assert_eq!(NonSmallInt::triangular(&of(1000)), summed);
+        let big = NonSmallInt::parse("1000000000000000000000").unwrap();
+        assert_eq!(NonSmallInt::triangular(&big), NonSmallInt::parse("500000000000000000000500000000000000000000").unwrap());
+        assert_eq!(of(999).succ(), of(1000));
+    }
+
+    #[test]
+    fn sum_of_squares_closed_form() {
+        let of = NonSmallInt::of;
+        assert_eq!(NonSmallInt::sum_of_squares(&of(10)), of(385));
+        assert_eq!(NonSmallInt::sum_of_squares(&of(0)), of(0));
+        assert_eq!(of(12).div_exact(&of(4)), Some(of(3)));
+        assert_eq!(of(12).div_exact(&of(5)), None);
+        assert_eq!(of(12).div_exact(&of(0)), None);
+    }
+
+    #[test]
+    fn powers_of_two() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(2).powers().take(5).collect::<Vec<_>>(), vec![of(1), of(2), of(4), of(8), of(16)]);
+        assert_eq!(of(0).powers().take(3).collect::<Vec<_>>(), vec![of(1), of(0), of(0)]);
+    }
+
+    #[test]
+    fn fibonacci_numbers() {
+        assert!(NonSmallInt::of(55).is_fibonacci());
+        assert!(!NonSmallInt::of(54).is_fibonacci());
+        let fibonacci: Vec<u64> = (0..100).filter(|&n| NonSmallInt::of(n).is_fibonacci()).collect();
+        assert_eq!(fibonacci, vec![0, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89]);
+        assert!(NonSmallInt::of(12200160415121876738).is_fibonacci());
+        assert!(!NonSmallInt::of(12200160415121876739).is_fibonacci());
+    }
+
+    #[test]
+    fn binomials_and_catalans() {
+        let of = NonSmallInt::of;
+        assert_eq!(NonSmallInt::binomial(5, 2), of(10));
+        assert_eq!(NonSmallInt::binomial(5, 0), of(1));
+        assert_eq!(NonSmallInt::binomial(5, 6), of(0));
+        assert_eq!(NonSmallInt::binomial(64, 32), of(1832624140942590534));
+        let catalans: Vec<NonSmallInt> = (0..6).map(NonSmallInt::catalan).collect();
+        assert_eq!(catalans, vec![of(1), of(1), of(2), of(5), of(14), of(42)]);
+        assert_eq!(NonSmallInt::catalan(10), of(16796));
+    }
+
+    #[test]
+    fn fixed_width_display() {
+        let of = NonSmallInt::of;
+        assert_eq!(FixedWidth::new(of(42), 6).unwrap().to_string(), "000042");
+        assert_eq!(FixedWidth::new(of(123456), 6).unwrap().to_string(), "123456");
+        assert_eq!(FixedWidth::new(of(0), 3).unwrap().to_string(), "000");
+        assert_eq!(FixedWidth::new(NonSmallInt::parse("00042").unwrap(), 2).unwrap().to_string(), "42");
+        assert_eq!(FixedWidth::new(of(1234567), 6), None);
+        // Independent of the formatter's width flag
+        assert_eq!(format!("{:10}", FixedWidth::new(of(7), 3).unwrap()), "007");
+    }
+
+    #[test]
+    fn digital_root_in_radix() {
+        // 0xFF -> 0xF + 0xF = 0x1E -> 0x1 + 0xE = 0xF
+        assert_eq!(NonSmallInt::of(255).digital_root_radix(16), 15);
+        assert_eq!(NonSmallInt::of(256).digital_root_radix(16), 1);
+        assert_eq!(NonSmallInt::of(12345).digital_root_radix(10), 6);
+        assert_eq!(NonSmallInt::of(0).digital_root_radix(2), 0);
+        assert_eq!(NonSmallInt::of(6).digital_root_radix(2), 1);
+    }
+
+    #[test]
+    fn factorial_mod_wilson() {
+        let of = NonSmallInt::of;
+        assert_eq!(NonSmallInt::factorial_mod(4, &of(5)), of(4));
+        // Wilson's theorem: (p - 1)! = -1 (mod p) exactly for primes
+        for p in 2..60u64 {
+            let is_wilson = NonSmallInt::factorial_mod(p - 1, &of(p)) == of(p - 1);
+            assert_eq!(is_wilson, of(p).is_probable_prime(12), "p = {}", p);
+        }
+        assert_eq!(NonSmallInt::factorial_mod(100, &of(1000000007)), &NonSmallInt::factorial(100) % &of(1000000007));
+        assert_eq!(NonSmallInt::factorial_mod(0, &of(1)), of(0));
+    }
+
+    #[test]
+    fn jacobi_symbols() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(1001).jacobi_symbol(&of(9907)), -1);
+        assert_eq!(of(19).jacobi_symbol(&of(45)), 1);
+        assert_eq!(of(8).jacobi_symbol(&of(21)), -1);
+        assert_eq!(of(5).jacobi_symbol(&of(21)), 1);
+        assert_eq!(of(6).jacobi_symbol(&of(9)), 0);
+        assert_eq!(of(0).jacobi_symbol(&of(1)), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn jacobi_symbol_rejects_even_n() {
+        NonSmallInt::of(3).jacobi_symbol(&NonSmallInt::of(10));
+    }
+
+    #[test]
+    fn english_words() {
+        let words = |n: u64| NonSmallInt::of(n).to_english_words();
+        assert_eq!(words(0), "zero");
+        assert_eq!(words(7), "seven");
+        assert_eq!(words(13), "thirteen");
+        assert_eq!(words(40), "forty");
+        assert_eq!(words(99), "ninety-nine");
+        assert_eq!(words(100), "one hundred");
+        assert_eq!(words(1234), "one thousand two hundred thirty-four");
+        assert_eq!(words(1000000), "one million");
+        assert_eq!(words(2000017), "two million seventeen");
+        assert_eq!(words(u64::MAX), "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four \
+            trillion seventy-three billion seven hundred nine million five hundred fifty-one thousand six hundred fifteen");
+        let vigintillion = NonSmallInt::of(10).pow(63);
+        assert_eq!(vigintillion.to_english_words(), "one vigintillion");
+        assert_eq!((&vigintillion * 1000u32).to_english_words(), "number too large");
+    }
+
+    #[test]
+    fn perfect_powers() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(64).is_perfect_power(), Some((of(8), 2)));
+        assert_eq!(of(27).is_perfect_power(), Some((of(3), 3)));
+        assert_eq!(of(32).is_perfect_power(), Some((of(2), 5)));
+        assert_eq!(of(63).is_perfect_power(), None);
+        assert_eq!(of(2).is_perfect_power(), None);
+        assert_eq!(of(1).is_perfect_power(), Some((of(1), 2)));
+        assert_eq!(of(7).pow(20).is_perfect_power(), Some((of(7).pow(10), 2)));
+        assert_eq!(of(3).pow(7).is_perfect_power(), Some((of(3), 7)));
+    }
+
+    #[test]
+    fn totient_bruteforce() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(12).totient_bruteforce_bound(100), Some(of(4)));
+        assert_eq!(of(13).totient_bruteforce_bound(100), Some(of(12)));
+        assert_eq!(of(1).totient_bruteforce_bound(100), Some(of(1)));
+        assert_eq!(of(0).totient_bruteforce_bound(100), Some(of(0)));
+        assert_eq!(of(101).totient_bruteforce_bound(100), None);
+    }
+
+    #[test]
+    fn aliquot_sums() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(220).aliquot_sum(1000), Some(of(284)));
+        assert_eq!(of(284).aliquot_sum(1000), Some(of(220)));
+        assert_eq!(of(28).aliquot_sum(1000), Some(of(28)));
+        assert_eq!(of(13).aliquot_sum(1000), Some(of(1)));
+        assert_eq!(of(16).aliquot_sum(1000), Some(of(15)));
+        assert_eq!(of(1).aliquot_sum(1000), Some(of(0)));
+        assert_eq!(of(0).aliquot_sum(1000), None);
+        assert_eq!(of(1001).aliquot_sum(1000), None);
+    }
+
+    #[test]
+    fn amicable_pairs() {
+        let of = NonSmallInt::of;
+        assert!(of(220).is_amicable_with(&of(284), 1000));
+        assert!(of(284).is_amicable_with(&of(220), 1000));
+        assert!(of(1184).is_amicable_with(&of(1210), 10000));
+        assert!(!of(28).is_amicable_with(&of(28), 1000));
+        assert!(!of(220).is_amicable_with(&of(285), 1000));
+        assert!(!of(220).is_amicable_with(&of(284), 250));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_range_stays_in_range() {
+        let mut rng = rand::thread_rng();
+        let low = NonSmallInt::parse("100000000000000000000").unwrap();
+        let high = NonSmallInt::parse("100000000000000000125").unwrap();
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..3000 {
+            let n = NonSmallInt::random_range(&mut rng, &low, &high);
+            assert!(low <= n && n <= high);
+            seen.insert(n.to_string());
+        }
+        // All 126 values show up with overwhelming probability
+        assert_eq!(seen.len(), 126);
+
+        let one = NonSmallInt::of(1);
+        assert_eq!(NonSmallInt::random_range(&mut rng, &one, &one), one);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    #[should_panic]
+    fn random_range_rejects_empty_range() {
+        NonSmallInt::random_range(&mut rand::thread_rng(), &NonSmallInt::of(2), &NonSmallInt::of(1));
+    }
+
+    #[test]
+    fn decimal_periods() {
+        let period = |n: u64| NonSmallInt::decimal_period(&NonSmallInt::of(n), 1000);
+        assert_eq!(period(7), Some(6));
+        assert_eq!(period(3), Some(1));
+        assert_eq!(period(12), Some(1));
+        assert_eq!(period(28), Some(6));
+        assert_eq!(period(8), Some(0));
+        assert_eq!(period(1), Some(0));
+        assert_eq!(period(97), Some(96));
+        assert_eq!(period(0), None);
+        assert_eq!(NonSmallInt::decimal_period(&NonSmallInt::of(97), 95), None);
+        assert_eq!(NonSmallInt::decimal_period(&NonSmallInt::of(97), 96), Some(96));
+        assert_eq!(NonSmallInt::decimal_period(&NonSmallInt::of(3), 0), None);
+        assert_eq!(NonSmallInt::decimal_period(&NonSmallInt::of(3), 1), Some(1));
+        assert_eq!(NonSmallInt::decimal_period(&NonSmallInt::of(7), 5), None);
+        assert_eq!(NonSmallInt::decimal_period(&NonSmallInt::of(8), 0), Some(0));
+    }
+
+    #[test]
+    fn range_sums() {
+        let of = NonSmallInt::of;
+        assert_eq!(NonSmallInt::range_sum(&of(10), &of(20)), Some(of(165)));
+        assert_eq!(NonSmallInt::range_sum(&of(0), &of(100)), Some(of(5050)));
+        assert_eq!(NonSmallInt::range_sum(&of(7), &of(7)), Some(of(7)));
+        assert_eq!(NonSmallInt::range_sum(&of(8), &of(7)), None);
+    }
+
+    #[test]
+    fn mod97_iban() {
+        // GB82 WEST 1234 5698 7654 32 rearranged with letters converted to digits
+        let iban = NonSmallInt::parse("3214282912345698765432161182").unwrap();
+        assert_eq!(iban.mod97(), 1);
+        assert_eq!(NonSmallInt::parse("3214282912345698765432161183").unwrap().mod97(), 2);
+        assert_eq!(NonSmallInt::of(0).mod97(), 0);
+        assert_eq!(NonSmallInt::of(97).mod97(), 0);
+    }
+
+    #[test]
+    fn repeated_squares() {
+        assert_eq!(NonSmallInt::of(2).repeated_square(3), NonSmallInt::of(256));
+        assert_eq!(NonSmallInt::of(7).repeated_square(0), NonSmallInt::of(7));
+        assert_eq!(NonSmallInt::of(3).repeated_square(4), NonSmallInt::of(43046721));
+    }
+
+    #[test]
+    fn binary_hamming_distances() {
+        assert_eq!(NonSmallInt::of(5).binary_hamming_distance(&NonSmallInt::of(6)), 2);
+        assert_eq!(NonSmallInt::of(0).binary_hamming_distance(&NonSmallInt::of(8)), 1);
+        assert_eq!(NonSmallInt::of(9).binary_hamming_distance(&NonSmallInt::of(9)), 0);
+    }
+
+    #[test]
+    fn checked_division() {
+        let (x, zero) = (NonSmallInt::of(1234), NonSmallInt::of(0));
+        assert_eq!(x.checked_div(&zero), Err(DivError::DivisionByZero));
+        assert_eq!(x.checked_rem(&zero), Err(DivError::DivisionByZero));
+        assert_eq!(x.checked_div(&NonSmallInt::of(100)), Ok(NonSmallInt::of(12)));
+        assert_eq!(x.checked_rem(&NonSmallInt::of(100)), Ok(NonSmallInt::of(34)));
+        assert_eq!(DivError::DivisionByZero.to_string(), "Division by zero is not allowed");
+    }
+
+    #[test]
+    fn dot_products() {
+        let of = |ns: &[u64]| ns.iter().map(|&n| NonSmallInt::of(n)).collect::<Vec<_>>();
+        assert_eq!(NonSmallInt::dot_product(&of(&[2, 3]), &of(&[4, 5])), Some(NonSmallInt::of(23)));
+        assert_eq!(NonSmallInt::dot_product(&[], &[]), Some(NonSmallInt::of(0)));
+        assert_eq!(NonSmallInt::dot_product(&of(&[2, 3]), &of(&[4])), None);
+    }
+
+    #[test]
+    fn binomials_mod_prime() {
+        for &p in &[2, 3, 5, 7, 13] {
+            for n in 0..30 {
+                for k in 0..32 {
+                    let expected = NonSmallInt::binomial(n, k).rem_u32(p);
+                    assert_eq!(NonSmallInt::binomial_mod_prime(&NonSmallInt::of(n), &NonSmallInt::of(k), p), expected);
+                }
+            }
+        }
+        let n = NonSmallInt::parse("100000000000000000000").unwrap();
+        assert_eq!(NonSmallInt::binomial_mod_prime(&n, &NonSmallInt::of(3), 4294967291), {
+            // 10^20 * (10^20 - 1) * (10^20 - 2) / 6, reduced with u128 arithmetic
+            let (n, p) = (100000000000000000000u128 % 4294967291, 4294967291u128);
+            let inverse_of_six = NonSmallInt::of(6).mod_inverse(&NonSmallInt::of(p as u64)).unwrap().to_u128_saturating();
+            (n * ((n + p - 1) % p) % p * ((n + p - 2) % p) % p * inverse_of_six % p) as u32
+        });
+    }
+
+    #[test]
+    fn carries_across_digits() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(19).carries_when_adding(&of(1)), 1);
+        assert_eq!(of(999).carries_when_adding(&of(1)), 3);
+        assert_eq!(of(123).carries_when_adding(&of(456)), 0);
+        assert_eq!(of(0).carries_when_adding(&of(0)), 0);
+    }
+
+    #[test]
+    fn factorial_prime_valuations() {
+        assert_eq!(NonSmallInt::factorial_prime_valuation(10, 2), 8);
+        assert_eq!(NonSmallInt::factorial_prime_valuation(10, 5), 2);
+        assert_eq!(NonSmallInt::factorial_prime_valuation(100, 5), 24);
+        assert_eq!(NonSmallInt::factorial_prime_valuation(1, 2), 0);
+    }
+
+    #[test]
+    fn removes_factors() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(48).remove_factor(&of(2)), (of(3), 4));
+        assert_eq!(of(48).remove_factor(&of(4)), (of(3), 2));
+        assert_eq!(of(35).remove_factor(&of(3)), (of(35), 0));
+        assert_eq!(of(0).remove_factor(&of(10)), (of(0), 0));
+        let (rest, threes) = NonSmallInt::factorial(20).remove_factor(&of(3));
+        assert_eq!(threes as u64, NonSmallInt::factorial_prime_valuation(20, 3));
+        assert!(!rest.is_divisible_by(&of(3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_factor_rejects_one() {
+        NonSmallInt::of(12).remove_factor(&NonSmallInt::of(1));
+    }
+
+    #[test]
+    fn cube_roots() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(27).icbrt(), of(3));
+        assert_eq!(of(28).icbrt(), of(3));
+        assert_eq!(of(26).icbrt(), of(2));
+        assert_eq!(of(0).icbrt(), of(0));
+        assert_eq!(of(1).icbrt(), of(1));
+        assert_eq!(of(999).icbrt(), of(9));
+        assert_eq!(of(1000).icbrt(), of(10));
+        assert_eq!(NonSmallInt::parse("1000000000000000000000000000000").unwrap().icbrt(), of(10000000000));
+    }
+
+    #[test]
+    fn maps_digits() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(123).map_digits(|d| 9 - d), of(876));
+        assert_eq!(of(987).map_digits(|d| 9 - d), of(12));
+        assert_eq!(of(456).map_digits(|d| d + 7), of(123));
+        assert_eq!(of(0).map_digits(|d| d + 1), of(1));
+        assert_eq!(of(0).map_digits(|d| 9 - d), of(9));
+        assert_eq!(of(5).map_digits(|_| 0), of(0));
+    }
+
+    #[test]
+    fn smallest_repunit_multiples() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(3).smallest_repunit_multiple(100), Some(3));
+        assert_eq!(of(1).smallest_repunit_multiple(100), Some(1));
+        assert_eq!(of(7).smallest_repunit_multiple(100), Some(6));
+        assert_eq!(of(41).smallest_repunit_multiple(100), Some(5));
+        assert_eq!(of(7).smallest_repunit_multiple(5), None);
+        assert_eq!(of(10).smallest_repunit_multiple(100), None);
+        assert_eq!(of(0).smallest_repunit_multiple(100), None);
+    }
+
+    #[test]
+    fn consecutive_sum_representations() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(15).consecutive_sum_representations(100), vec![(of(7), 2), (of(4), 3), (of(1), 5)]);
+        assert_eq!(of(15).consecutive_sum_representations(3), vec![(of(7), 2), (of(4), 3)]);
+        assert_eq!(of(16).consecutive_sum_representations(100), vec![]);
+        assert_eq!(of(3).consecutive_sum_representations(100), vec![(of(1), 2)]);
+        assert_eq!(of(0).consecutive_sum_representations(100), vec![]);
+    }
+
+    #[test]
+    fn verhoeff_check_digits() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(236).verhoeff_check_digit(), 3);
+        assert_eq!(of(12345).verhoeff_check_digit(), 1);
+        assert_eq!(of(0).verhoeff_check_digit(), 0);
+        assert_eq!(NonSmallInt::parse("00236").unwrap().verhoeff_check_digit(), 3);
+    }
+
+    #[test]
+    fn trailing_nines() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(12999).trailing_nines(), 3);
+        assert_eq!(of(120).trailing_nines(), 0);
+        assert_eq!(of(999).trailing_nines(), 3);
+        assert_eq!(of(0).trailing_nines(), 0);
+    }
+
+    #[test]
+    fn linear_recurrence_sums() {
+        let of = NonSmallInt::of;
+        let fibonacci_seed = [of(0), of(1)];
+        // 0 + 1 + 1 + 2 + 3 + 5 + 8 + 13 + 21 + 34 == F(11) - 1
+        assert_eq!(NonSmallInt::linear_recurrence_sum(&[1, 1], &fibonacci_seed, 10), of(88));
+        assert_eq!(NonSmallInt::linear_recurrence_sum(&[1, 1], &fibonacci_seed, 1), of(0));
+        assert_eq!(NonSmallInt::linear_recurrence_sum(&[1, 1], &fibonacci_seed, 0), of(0));
+        assert_eq!(NonSmallInt::linear_recurrence_sum(&[1, 1], &fibonacci_seed, 100), NonSmallInt::parse("573147844013817084100").unwrap());
+        // Powers of three: 1 + 3 + 9 + 27
+        assert_eq!(NonSmallInt::linear_recurrence_sum(&[3], &[of(1)], 4), of(40));
+    }
+
+    #[test]
+    fn drops_leading_digits() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(123456).drop_leading(2), of(3456));
+        assert_eq!(of(123456).drop_leading(0), of(123456));
+        assert_eq!(of(103456).drop_leading(1), of(3456));
+        assert_eq!(of(123456).drop_leading(6), of(0));
+        assert_eq!(of(123456).drop_leading(10), of(0));
+        assert_eq!(NonSmallInt::parse("00123").unwrap().drop_leading(1), of(23));
+    }
+
+    #[test]
+    fn digit_anagrams() {
+        let of = NonSmallInt::of;
+        assert!(of(125874).is_digit_anagram(&of(251748)));
+        assert!(of(0).is_digit_anagram(&of(0)));
+        assert!(of(1001).is_digit_anagram(&of(1100)));
+        assert!(!of(1001).is_digit_anagram(&of(110)));
+        assert!(!of(112).is_digit_anagram(&of(122)));
+        assert!(NonSmallInt::parse("0021").unwrap().is_digit_anagram(&of(12)));
+    }
+
+    #[test]
+    fn pandigitals() {
+        let of = NonSmallInt::of;
+        assert!(of(123456789).is_pandigital(9));
+        assert!(of(918273645).is_pandigital(9));
+        assert!(of(2143).is_pandigital(4));
+        assert!(!of(2143).is_pandigital(5));
+        assert!(!of(1123456789).is_pandigital(9));
+        assert!(!of(1023456789).is_pandigital(9));
+        assert!(!of(0).is_pandigital(0));
+        assert!(of(1023456789).is_pandigital_0_to_9());
+        assert!(!of(123456789).is_pandigital_0_to_9());
+        assert!(!of(10023456789).is_pandigital_0_to_9());
+    }
+
+    #[test]
+    fn smallest_with_digit_counts() {
+        let of = NonSmallInt::of;
+        let counts = |ds: &[usize]| ds.iter().fold([0; 10], |mut acc, &d| { acc[d] += 1; acc });
+        assert_eq!(NonSmallInt::smallest_with_digit_counts(&counts(&[1, 0])), Some(of(10)));
+        assert_eq!(NonSmallInt::smallest_with_digit_counts(&counts(&[3, 0, 0, 1, 3])), Some(of(10033)));
+        assert_eq!(NonSmallInt::smallest_with_digit_counts(&counts(&[7])), Some(of(7)));
+        assert_eq!(NonSmallInt::smallest_with_digit_counts(&counts(&[0])), Some(of(0)));
+        assert_eq!(NonSmallInt::smallest_with_digit_counts(&counts(&[0, 0])), None);
+        assert_eq!(NonSmallInt::smallest_with_digit_counts(&counts(&[])), None);
+    }
+
+    #[test]
+    fn sorted_digit_arrangements() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(2130).digits_descending(), of(3210));
+        assert_eq!(of(2130).digits_ascending(), of(123));
+        assert_eq!(of(7).digits_descending(), of(7));
+        assert_eq!(of(0).digits_ascending(), of(0));
+        assert_eq!(NonSmallInt::parse("0021").unwrap().digits_descending(), of(21));
+    }
+
+    #[test]
+    fn digit_differences() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(1357).digit_differences(), vec![2, 2, 2]);
+        assert_eq!(of(9051).digit_differences(), vec![-9, 5, -4]);
+        assert_eq!(of(7).digit_differences(), vec![]);
+        assert_eq!(of(0).digit_differences(), vec![]);
+    }
+
+    #[test]
+    fn polynomial_hashes() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(123).polynomial_hash(31, 1000000007), of(123).polynomial_hash(31, 1000000007));
+        assert_ne!(of(123).polynomial_hash(31, 1000000007), of(132).polynomial_hash(31, 1000000007));
+        // 1 * 31^2 + 2 * 31 + 3
+        assert_eq!(of(123).polynomial_hash(31, 1000000007), 1026);
+        assert_eq!(of(123).polynomial_hash(31, 7), 1026 % 7);
+        assert_eq!(of(0).polynomial_hash(31, 7), 0);
+    }
+
+    #[test]
+    fn mod11_alternating_sums() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(121).mod11(), 0);
+        assert_eq!(of(918082).mod11(), 918082 % 11);
+        assert_eq!(of(10).mod11(), 10);
+        assert_eq!(of(0).mod11(), 0);
+        assert_eq!(NonSmallInt::parse("000121").unwrap().mod11(), 0);
+    }
+
+    #[test]
+    fn bitwise_ops_on_small_values() {
+        let of = NonSmallInt::of;
+        assert_eq!(&of(12) & &of(10), of(8));
+        assert_eq!(&of(12) | &of(10), of(14));
+        assert_eq!(&of(12) ^ &of(10), of(6));
+        assert_eq!(of(12).bitand(&of(0)), of(0));
+        assert_eq!(of(0).bitor(&of(5)), of(5));
+        assert_eq!(of(5).bitxor(&of(5)), of(0));
+    }
+
+    #[test]
+    fn single_bits_of_small_values() {
+        let of = NonSmallInt::of;
+        assert!(of(5).test_bit(0));
+        assert!(!of(5).test_bit(1));
+        assert!(!of(5).test_bit(100));
+        assert_eq!(of(5).set_bit(1), of(7));
+        assert_eq!(of(5).clear_bit(2), of(1));
+        assert_eq!(of(5).clear_bit(10), of(5));
+        assert_eq!(of(0).set_bit(70), NonSmallInt::parse("1180591620717411303424").unwrap());
+    }
+
+    #[test]
+    fn scaled_log10() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(2).log10_scaled(4), of(3010));
+        assert_eq!(of(1000).log10_scaled(3), of(3000));
+        assert_eq!(of(999).log10_scaled(0), of(2));
+        assert_eq!(of(1).log10_scaled(5), of(0));
+        assert_eq!(of(2).log10_scaled(18), of(301029995663981000));
+        // log10(2^100) = 30.1029995663981...
+        assert_eq!(of(2).pow(100).log10_scaled(6), of(30102999));
+    }
+
+    #[test]
+    fn increment_digit_churns() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(1999).increment_digit_churn(), 4);
+        assert_eq!(of(999).increment_digit_churn(), 4);
+        assert_eq!(of(1234).increment_digit_churn(), 1);
+        assert_eq!(of(0).increment_digit_churn(), 1);
+    }
+
+    #[test]
+    fn with_suffixes() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(12).with_suffix(5, 3), of(12005));
+        assert_eq!(of(12).with_suffix(345, 3), of(12345));
+        assert_eq!(of(12).with_suffix(0, 0), of(12));
+        assert_eq!(of(0).with_suffix(7, 2), of(7));
+        assert_eq!(of(1).with_suffix(u64::MAX, 25), NonSmallInt::parse("10000018446744073709551615").unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_suffix_rejects_oversized_suffix() {
+        NonSmallInt::of(12).with_suffix(1000, 3);
+    }
+
+    #[test]
+    fn rotation_sums() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(142857).rotation_sum(), of(2999997));
+        assert_eq!(of(10).rotation_sum(), of(11));
+        assert_eq!(of(1212).rotation_sum(), of(3333));
+        assert_eq!(of(777).rotation_sum(), of(777));
+        assert_eq!(of(0).rotation_sum(), of(0));
+    }
+
+    #[test]
+    fn repunit_divisor_lengths() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(7).repunit_divisor_length(100), Some(6));
+        assert_eq!(of(17).repunit_divisor_length(100), Some(16));
+        assert_eq!(of(13).repunit_divisor_length(100), Some(6));
+        assert_eq!(of(6).repunit_divisor_length(100), None);
+        let order = of(10).multiplicative_order(&of(9 * 7)).unwrap();
+        assert_eq!(order, of(6));
+    }
+
+    #[test]
+    fn modular_square_roots() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(2).mod_sqrt(&of(7)), Some(of(3)));
+        assert_eq!(of(3).mod_sqrt(&of(7)), None);
+        assert_eq!(of(0).mod_sqrt(&of(7)), Some(of(0)));
+        assert_eq!(of(14).mod_sqrt(&of(7)), Some(of(0)));
+        // 17 - 1 = 2^4, which takes the full Tonelli-Shanks loop
+        for a in 1..17 {
+            let square = of(a * a % 17);
+            assert_eq!(square.mod_sqrt(&of(17)), Some(min(of(a), of(17 - a))));
+        }
+        assert_eq!(of(5).mod_sqrt(&of(41)), Some(of(13)));
+    }
+
+    #[test]
+    fn negabinary() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(6).to_negabinary(), vec![0, 1, 0, 1, 1]);
+        assert_eq!(of(2).to_negabinary(), vec![0, 1, 1]);
+        assert_eq!(of(0).to_negabinary(), Vec::<u8>::new());
+        assert_eq!(NonSmallInt::from_negabinary(&[0, 1, 0, 1, 1]), Some(of(6)));
+        assert_eq!(NonSmallInt::from_negabinary(&[]), Some(of(0)));
+        assert_eq!(NonSmallInt::from_negabinary(&[0, 1]), None);
+        assert_eq!(NonSmallInt::from_negabinary(&[2]), None);
+    }
+
+    #[test]
+    fn continued_fractions_of_ratios() {
+        let of = NonSmallInt::of;
+        assert_eq!(NonSmallInt::continued_fraction_of_ratio(&of(415), &of(93)), vec![of(4), of(2), of(6), of(7)]);
+        assert_eq!(NonSmallInt::continued_fraction_of_ratio(&of(93), &of(415)), vec![of(0), of(4), of(2), of(6), of(7)]);
+        assert_eq!(NonSmallInt::continued_fraction_of_ratio(&of(6), &of(3)), vec![of(2)]);
+        assert_eq!(NonSmallInt::continued_fraction_of_ratio(&of(6), &of(0)), vec![]);
+    }
+
+    #[test]
+    fn best_rational_approximations() {
+        let of = NonSmallInt::of;
+        let (pi_num, pi_den) = (of(314159265358979), of(100000000000000));
+        assert_eq!(NonSmallInt::best_rational_approx(&pi_num, &pi_den, &of(1000)), (of(355), of(113)));
+        assert_eq!(NonSmallInt::best_rational_approx(&pi_num, &pi_den, &of(100)), (of(311), of(99)));
+        assert_eq!(NonSmallInt::best_rational_approx(&pi_num, &pi_den, &of(10)), (of(22), of(7)));
+        assert_eq!(NonSmallInt::best_rational_approx(&of(415), &of(93), &of(100)), (of(415), of(93)));
+        assert_eq!(NonSmallInt::best_rational_approx(&of(415), &of(93), &of(10)), (of(40), of(9)));
+        assert_eq!(NonSmallInt::best_rational_approx(&of(6), &of(4), &of(10)), (of(3), of(2)));
+        assert_eq!(NonSmallInt::best_rational_approx(&of(1), &of(3), &of(2)), (of(1), of(2)));
+        assert_eq!(NonSmallInt::best_rational_approx(&of(0), &of(3), &of(2)), (of(0), of(1)));
+    }
+
+    #[test]
+    fn positional_checksums() {
+        let of = NonSmallInt::of;
+        // 4 * 1 + 3 * 2 + 2 * 3 + 1 * 4
+        assert_eq!(of(1234).positional_checksum(1000), 20);
+        assert_eq!(of(1234).positional_checksum(7), 20 % 7);
+        assert_ne!(of(1234).positional_checksum(1000), of(1324).positional_checksum(1000));
+        assert_ne!(of(1234).positional_checksum(1000), of(2134).positional_checksum(1000));
+        assert_eq!(of(0).positional_checksum(7), 0);
+    }
+
+    #[test]
+    fn factors_out_twos() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(40).factor_out_twos(), (of(5), 3));
+        assert_eq!(of(7).factor_out_twos(), (of(7), 0));
+        assert_eq!(of(1).factor_out_twos(), (of(1), 0));
+        assert_eq!(of(0).factor_out_twos(), (of(0), 0));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_input() {
+        assert_eq!("".parse::<NonSmallInt>(), Err(ParseNonSmallIntError::Empty));
+        assert_eq!(" \t\n".parse::<NonSmallInt>(), Err(ParseNonSmallIntError::Empty));
+        assert_eq!("12a3".parse::<NonSmallInt>(), Err(ParseNonSmallIntError::InvalidDigit(2)));
+        assert_eq!("  -5".parse::<NonSmallInt>(), Err(ParseNonSmallIntError::InvalidDigit(2)));
+        assert_eq!("1 2".parse::<NonSmallInt>(), Err(ParseNonSmallIntError::InvalidDigit(1)));
+        assert_eq!(NonSmallInt::parse(""), None);
+        assert_eq!(NonSmallInt::parse("   "), None);
+        assert_eq!(NonSmallInt::from_ascii(b""), None);
+        assert_eq!(ParseNonSmallIntError::InvalidDigit(2).to_string(), "Invalid digit at position 2");
+    }
+
+    #[test]
+    fn parse_normalizes_leading_zeros() {
+        assert_eq!("007".parse::<NonSmallInt>().unwrap().digits, vec![7]);
+        assert_eq!(NonSmallInt::parse(" 000 ").unwrap().digits, Vec::<u8>::new());
+        assert_eq!(NonSmallInt::from_ascii(b"0042").unwrap().digits, vec![2, 4]);
+        assert_eq!(NonSmallInt::of(0).digits, Vec::<u8>::new());
+        assert_eq!(NonSmallInt::of(120).digits, vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn divisors_by_trial_division() {
+        let of = NonSmallInt::of;
+        assert_eq!(of(28).divisors(100), Some(vec![of(1), of(2), of(4), of(7), of(14), of(28)]));
+        assert_eq!(of(36).divisors(100), Some(vec![of(1), of(2), of(3), of(4), of(6), of(9), of(12), of(18), of(36)]));
+        assert_eq!(of(1).divisors(100), Some(vec![of(1)]));
+        assert_eq!(of(28).divisors(27), None);
+        assert_eq!(of(0).divisors(100), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn binomial_mod_prime_rejects_one() {
+        NonSmallInt::binomial_mod_prime(&NonSmallInt::of(5), &NonSmallInt::of(2), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn binomial_mod_prime_rejects_zero() {
+        NonSmallInt::binomial_mod_prime(&NonSmallInt::of(5), &NonSmallInt::of(2), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn digits_radix_rejects_radix_over_256() {
+        NonSmallInt::of(1000).digits_radix(257);
+    }
+
+    #[test]
+    #[should_panic]
+    fn digits_radix_rejects_radix_one() {
+        NonSmallInt::of(1000).digits_radix(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn digital_root_radix_rejects_radix_over_256() {
+        NonSmallInt::of(1000).digital_root_radix(1000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn polynomial_hash_rejects_zero_modulus() {
+        NonSmallInt::of(123).polynomial_hash(31, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn positional_checksum_rejects_zero_modulus() {
+        NonSmallInt::of(123).positional_checksum(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn grouped_digits_rejects_zero_size() {
+        NonSmallInt::of(12345678).grouped_digits(&[3, 0, 3]);
     }
 }